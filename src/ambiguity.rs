@@ -0,0 +1,27 @@
+use crate::{prelude::Enemy, rules::Rules};
+
+/// Groups of enemies that no guess can tell apart under `rules`, see
+/// [`crate::prelude::EnemyDiff::is_indistinguishable`].
+///
+/// Only groups of two or more are returned, each in dataset order. An answer
+/// from one of these groups can only be found by guessing its exact name, so
+/// they're worth leaving out of the daily schedule (see
+/// [`crate::daily::Schedule::with_exclude`]) or telling apart with another
+/// [`crate::rules::Column`].
+pub fn ambiguous_groups<'a>(enemies: &'a [Enemy], rules: &Rules) -> Vec<Vec<&'a Enemy>> {
+    let mut groups: Vec<Vec<&Enemy>> = vec![];
+    for enemy in enemies {
+        // Being indistinguishable is an equivalence, so comparing against
+        // the first member of each group is enough.
+        match groups
+            .iter_mut()
+            .find(|group| enemy.diff_with(group[0], rules).is_indistinguishable())
+        {
+            Some(group) => group.push(enemy),
+            None => groups.push(vec![enemy]),
+        }
+    }
+    groups.retain(|group| group.len() > 1);
+
+    groups
+}
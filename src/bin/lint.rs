@@ -0,0 +1,53 @@
+//! Checks an enemy dataset for mistakes.
+//!
+//! Usage: `lint [DATASET]`. Without a path, the built-in dataset is checked.
+//! Groups of enemies the default rules can't tell apart are listed too.
+//! Exits with a failure status if any errors were found.
+
+use std::process::ExitCode;
+
+use eowordle_lib::{
+    ambiguity::ambiguous_groups,
+    dataset::read,
+    enemies::ENEMIES,
+    validate::{validate, Severity},
+};
+
+fn main() -> ExitCode {
+    let enemies = match std::env::args().nth(1) {
+        Some(path) => match read(&path) {
+            Ok(enemies) => enemies,
+            Err(error) => {
+                eprintln!("{path}: {error}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => ENEMIES.to_vec(),
+    };
+
+    let issues = validate(&enemies);
+    for issue in &issues {
+        println!("{issue}");
+    }
+    let groups = ambiguous_groups(&enemies, &Default::default());
+    for group in &groups {
+        let names: Vec<_> = group.iter().map(|enemy| &*enemy.name).collect();
+        println!("ambiguous: {}", names.join(", "));
+    }
+    let errors = issues
+        .iter()
+        .filter(|issue| issue.severity() == Severity::Error)
+        .count();
+    println!(
+        "{} enemies, {errors} errors, {} warnings, {} ambiguous groups",
+        enemies.len(),
+        issues.len() - errors,
+        groups.len()
+    );
+
+    if errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -2,7 +2,10 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+use crate::prelude::Flag;
+
+/// Where an enemy spawns. When it spawns is a [`crate::times::Time`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum Biome {
     Any,
     Forest,
@@ -17,8 +20,6 @@ pub enum Biome {
     Graveyard,
     Dungeon,
     Margranite, // Granite or Marble
-    Day,
-    Night
 }
 
 impl Display for Biome {
@@ -39,10 +40,30 @@ impl Display for Biome {
             Graveyard => "Graveyard",
             Dungeon => "Dungeon",
             Margranite => "Marble or Granite",
-            Day => "Day",
-            Night => "Night",
         };
 
         write!(f, "{text}")
     }
 }
+
+impl Flag for Biome {
+    const ALL: &'static [Self] = &[
+        Biome::Any,
+        Biome::Forest,
+        Biome::Snow,
+        Biome::Jungle,
+        Biome::Desert,
+        Biome::Ocean,
+        Biome::Corruption,
+        Biome::Crimson,
+        Biome::Hallow,
+        Biome::Mushroom,
+        Biome::Graveyard,
+        Biome::Dungeon,
+        Biome::Margranite,
+    ];
+
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
@@ -0,0 +1,90 @@
+use std::{borrow::Cow, collections::HashMap, fmt::Display, sync::OnceLock};
+
+use crate::{categories::Category, enemies::ENEMIES, prelude::Enemy};
+
+/// Two enemies were given the same id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateId {
+    pub id: u16,
+    pub first: String,
+    pub second: String,
+}
+
+impl Display for DuplicateId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} and {} share the id {}",
+            self.first, self.second, self.id
+        )
+    }
+}
+
+impl std::error::Error for DuplicateId {}
+
+/// A set of enemies indexed by id.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Catalogue {
+    enemies: Cow<'static, [Enemy]>,
+    index: HashMap<u16, usize>,
+}
+
+impl Catalogue {
+    /// Fails on the first id that appears more than once.
+    pub fn new(enemies: impl Into<Cow<'static, [Enemy]>>) -> Result<Self, DuplicateId> {
+        let enemies = enemies.into();
+        let mut index = HashMap::with_capacity(enemies.len());
+        for (position, enemy) in enemies.iter().enumerate() {
+            if let Some(first) = index.insert(enemy.id, position) {
+                return Err(DuplicateId {
+                    id: enemy.id,
+                    first: enemies[first].name.to_string(),
+                    second: enemy.name.to_string(),
+                });
+            }
+        }
+
+        Ok(Self { enemies, index })
+    }
+    /// The whole built-in dataset, bosses included, see [`ENEMIES`]. It's
+    /// indexed on first use and shared from then on.
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<Catalogue> = OnceLock::new();
+        BUILTIN.get_or_init(|| Self::new(ENEMIES).expect("built-in enemy ids are unique"))
+    }
+    /// The built-in dataset without bosses: the pool of the classic game.
+    pub fn regular() -> &'static Self {
+        static REGULAR: OnceLock<Catalogue> = OnceLock::new();
+        REGULAR.get_or_init(|| Self::builtin().filter(|enemy| enemy.category != Category::Boss))
+    }
+    pub fn get(&self, id: u16) -> Option<&Enemy> {
+        self.index.get(&id).map(|&position| &self.enemies[position])
+    }
+    /// A catalogue of the enemies matching `predicate`, e.g. only bosses for a boss mode.
+    pub fn filter(&self, predicate: impl Fn(&Enemy) -> bool) -> Self {
+        let enemies: Vec<_> = self
+            .enemies
+            .iter()
+            .filter(|enemy| predicate(enemy))
+            .cloned()
+            .collect();
+
+        Self::new(enemies).expect("a subset of unique ids is unique")
+    }
+    /// Every enemy, in dataset order.
+    pub fn enemies(&self) -> &[Enemy] {
+        &self.enemies
+    }
+    pub fn len(&self) -> usize {
+        self.enemies.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.enemies.is_empty()
+    }
+}
+
+impl AsRef<[Enemy]> for Catalogue {
+    fn as_ref(&self) -> &[Enemy] {
+        &self.enemies
+    }
+}
@@ -0,0 +1,27 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// Whether an enemy is a regular enemy or a (mini-)boss.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub enum Category {
+    #[default]
+    Regular,
+    /// Event and invasion bosses, such as the Pumpking or the Flying Dutchman.
+    MiniBoss,
+    Boss,
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Category::*;
+
+        let text = match self {
+            Regular => "Enemy",
+            MiniBoss => "Mini-boss",
+            Boss => "Boss",
+        };
+
+        write!(f, "{text}")
+    }
+}
@@ -0,0 +1,287 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::Enemy;
+
+/// A calendar date in the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct Date {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+    /// Returns `None` if the given day doesn't exist.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap => 29,
+            2 => 28,
+            _ => return None,
+        };
+
+        (1..=days_in_month)
+            .contains(&day)
+            .then_some(Self { year, month, day })
+    }
+    /// Number of days since 1970-01-01.
+    pub fn days(&self) -> i64 {
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = self.month as i64;
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+    /// The inverse of [`Date::days`].
+    pub fn from_days(days: i64) -> Self {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// Records that the dataset had `len` entries starting on `date`.
+///
+/// Enemies appended after `len` are never picked before `date`, so growing
+/// the dataset leaves past answers untouched.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Release {
+    pub date: Date,
+    pub len: usize,
+}
+
+/// Records that the enemies with these `ids` were added to one of the lists
+/// of a [`Schedule`] on `date`.
+///
+/// Days before `date` ignore the listing, so adding to a list leaves past
+/// answers untouched.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Listing {
+    pub date: Date,
+    pub ids: Vec<u16>,
+}
+
+/// Whether `id` is on a list made of `listings` on `date`.
+fn listed(listings: &[Listing], id: u16, date: Date) -> bool {
+    listings
+        .iter()
+        .any(|listing| listing.date <= date && listing.ids.contains(&id))
+}
+
+/// Maps calendar dates to daily answers.
+///
+/// Every answer is a pure function of the schedule, the dataset and the date:
+/// each day, the pool entry with the lowest hash of `(salt, day, id)` wins,
+/// skipping any enemy that was the answer within the last `window` days.
+///
+/// Enemies listed in `exclude` are never the answer. Enemies listed in
+/// `hard`, e.g. the ones [`crate::rating::report`] rates as hard, are not
+/// the answer two days in a row unless nothing else is left.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Schedule {
+    /// Day of puzzle #1.
+    pub epoch: Date,
+    pub salt: u64,
+    /// How many days must pass before an answer can repeat.
+    pub window: usize,
+    pub releases: Vec<Release>,
+    /// Enemies that shouldn't be the answer on consecutive days, if it can
+    /// be helped.
+    #[serde(default)]
+    pub hard: Vec<Listing>,
+    /// Enemies that are never the answer, e.g. ones found by
+    /// [`crate::ambiguity::ambiguous_groups`].
+    #[serde(default)]
+    pub exclude: Vec<Listing>,
+}
+
+impl Schedule {
+    /// A schedule picking from the first `dataset_len` enemies, starting on
+    /// `epoch`. Recording the size up front keeps past answers stable when
+    /// enemies are appended later, see [`Release`].
+    pub fn new(epoch: Date, dataset_len: usize) -> Self {
+        Self {
+            epoch,
+            salt: 0,
+            window: 0,
+            releases: vec![Release {
+                date: epoch,
+                len: dataset_len,
+            }],
+            hard: vec![],
+            exclude: vec![],
+        }
+    }
+    pub fn with_salt(mut self, salt: u64) -> Self {
+        self.salt = salt;
+        self
+    }
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window;
+        self
+    }
+    pub fn with_release(mut self, date: Date, len: usize) -> Self {
+        self.releases.push(Release { date, len });
+        self.releases.sort_by_key(|release| release.date);
+        self
+    }
+    pub fn with_hard(mut self, date: Date, ids: impl IntoIterator<Item = u16>) -> Self {
+        self.hard.push(Listing {
+            date,
+            ids: ids.into_iter().collect(),
+        });
+        self
+    }
+    pub fn with_exclude(mut self, date: Date, ids: impl IntoIterator<Item = u16>) -> Self {
+        self.exclude.push(Listing {
+            date,
+            ids: ids.into_iter().collect(),
+        });
+        self
+    }
+    /// Whether the enemy with `id` is listed as hard on `date`.
+    pub fn is_hard(&self, id: u16, date: Date) -> bool {
+        listed(&self.hard, id, date)
+    }
+    /// Whether the enemy with `id` is excluded on `date`.
+    pub fn is_excluded(&self, id: u16, date: Date) -> bool {
+        listed(&self.exclude, id, date)
+    }
+    /// The puzzle number for `date`, starting at 1 on the epoch.
+    pub fn puzzle(&self, date: Date) -> Option<u32> {
+        let day = date.days() - self.epoch.days();
+        u32::try_from(day + 1).ok().filter(|puzzle| *puzzle > 0)
+    }
+    /// How much of the dataset can be picked from on `date`. Without any
+    /// release on or before `date`, the whole dataset is used.
+    pub fn pool_len(&self, date: Date, dataset_len: usize) -> usize {
+        self.releases
+            .iter()
+            .rev()
+            .find(|release| release.date <= date)
+            .map_or(dataset_len, |release| release.len.min(dataset_len))
+    }
+    /// The answer for `date`, or `None` if it's before the epoch or there is nothing to pick.
+    pub fn answer<'a>(&self, enemies: &'a [Enemy], date: Date) -> Option<&'a Enemy> {
+        let day = date.days() - self.epoch.days();
+        if day < 0 {
+            return None;
+        }
+
+        self.answers(enemies).nth(day as usize).flatten()
+    }
+    /// Every answer from the epoch onwards, one per day.
+    pub fn answers<'a>(&self, enemies: &'a [Enemy]) -> Answers<'_, 'a> {
+        Answers {
+            schedule: self,
+            enemies,
+            day: self.epoch.days(),
+            recent: VecDeque::with_capacity(self.window.min(enemies.len())),
+            previous: None,
+        }
+    }
+    fn pick<'a>(
+        &self,
+        pool: &'a [Enemy],
+        date: Date,
+        recent: &VecDeque<u16>,
+        previous: Option<u16>,
+    ) -> Option<&'a Enemy> {
+        let seed = mix(mix(self.salt) ^ date.days() as u64);
+        let after_hard = previous.is_some_and(|id| self.is_hard(id, date));
+        let eligible = pool
+            .iter()
+            .filter(|enemy| !self.is_excluded(enemy.id, date))
+            .filter(|enemy| !recent.contains(&enemy.id));
+        let key = |enemy: &&Enemy| (mix(seed ^ enemy.id as u64), enemy.id);
+
+        // Two hard days in a row are only avoided while something else is left.
+        eligible
+            .clone()
+            .filter(|enemy| !after_hard || !self.is_hard(enemy.id, date))
+            .min_by_key(key)
+            .or_else(|| eligible.min_by_key(key))
+    }
+}
+
+/// Iterator over the daily answers of a [`Schedule`], see [`Schedule::answers`].
+pub struct Answers<'s, 'a> {
+    schedule: &'s Schedule,
+    enemies: &'a [Enemy],
+    day: i64,
+    recent: VecDeque<u16>,
+    previous: Option<u16>,
+}
+
+impl<'a> Iterator for Answers<'_, 'a> {
+    type Item = Option<&'a Enemy>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = Date::from_days(self.day);
+        let pool = &self.enemies[..self.schedule.pool_len(date, self.enemies.len())];
+        // A window as large as the pickable part of the pool would leave
+        // nothing to pick.
+        let pickable = pool
+            .iter()
+            .filter(|enemy| !self.schedule.is_excluded(enemy.id, date))
+            .count();
+        let window = self.schedule.window.min(pickable.saturating_sub(1));
+        while self.recent.len() > window {
+            self.recent.pop_front();
+        }
+
+        let answer = self.schedule.pick(pool, date, &self.recent, self.previous);
+        if let Some(enemy) = answer {
+            if window > 0 {
+                if self.recent.len() == window {
+                    self.recent.pop_front();
+                }
+                self.recent.push_back(enemy.id);
+            }
+        }
+        self.previous = answer.map(|enemy| enemy.id);
+        self.day += 1;
+
+        Some(answer)
+    }
+}
+
+/// SplitMix64 finaliser. Spelled out rather than using `std::hash` so that
+/// answers never change between compiler or crate versions.
+fn mix(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,145 @@
+use std::{fmt::Display, path::Path};
+
+#[cfg(feature = "toml")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    catalogue::Catalogue,
+    prelude::Enemy,
+    validate::{validate, Issue, Severity},
+};
+
+/// A file format enemy datasets can be read from and written to.
+///
+/// JSON and RON datasets hold a list of enemies at the top level. TOML has no
+/// top-level lists, so there the list goes under an `enemies` key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    #[cfg(feature = "ron")]
+    Ron,
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+impl Format {
+    /// Guesses the format from a file extension.
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?;
+        match extension.to_ascii_lowercase().as_str() {
+            "json" => Some(Format::Json),
+            #[cfg(feature = "ron")]
+            "ron" => Some(Format::Ron),
+            #[cfg(feature = "toml")]
+            "toml" => Some(Format::Toml),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(std::io::Error),
+    /// The file extension doesn't match any enabled [`Format`].
+    UnknownFormat,
+    Parse(String),
+    /// The dataset has errors, see [`validate`].
+    Invalid(Vec<Issue>),
+}
+
+impl Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LoadError::Io(error) => write!(f, "couldn't read dataset: {error}"),
+            LoadError::UnknownFormat => write!(f, "unknown dataset format"),
+            LoadError::Parse(error) => write!(f, "couldn't parse dataset: {error}"),
+            LoadError::Invalid(issues) => {
+                write!(f, "invalid dataset")?;
+                for issue in issues {
+                    write!(f, "\n{issue}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LoadError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for LoadError {
+    fn from(value: std::io::Error) -> Self {
+        LoadError::Io(value)
+    }
+}
+
+#[cfg(feature = "toml")]
+#[derive(Deserialize, Serialize)]
+struct Table<T> {
+    enemies: T,
+}
+
+/// Parses and validates a dataset. Warnings are let through, errors are not.
+pub fn parse(text: &str, format: Format) -> Result<Catalogue, LoadError> {
+    checked(parse_unchecked(text, format)?)
+}
+
+/// Reads a dataset, picking the format from the file extension.
+pub fn load(path: impl AsRef<Path>) -> Result<Catalogue, LoadError> {
+    checked(read(path)?)
+}
+
+/// Parses a dataset without validating it.
+pub fn parse_unchecked(text: &str, format: Format) -> Result<Vec<Enemy>, LoadError> {
+    let enemies = match format {
+        Format::Json => serde_json::from_str(text).map_err(|e| LoadError::Parse(e.to_string()))?,
+        #[cfg(feature = "ron")]
+        Format::Ron => ron::from_str(text).map_err(|e| LoadError::Parse(e.to_string()))?,
+        #[cfg(feature = "toml")]
+        Format::Toml => {
+            toml::from_str::<Table<_>>(text)
+                .map_err(|e| LoadError::Parse(e.to_string()))?
+                .enemies
+        }
+    };
+
+    Ok(enemies)
+}
+
+/// Reads a dataset without validating it.
+pub fn read(path: impl AsRef<Path>) -> Result<Vec<Enemy>, LoadError> {
+    let format = Format::from_path(&path).ok_or(LoadError::UnknownFormat)?;
+    let text = std::fs::read_to_string(path)?;
+
+    parse_unchecked(&text, format)
+}
+
+fn checked(enemies: Vec<Enemy>) -> Result<Catalogue, LoadError> {
+    let errors: Vec<_> = validate(&enemies)
+        .into_iter()
+        .filter(|issue| issue.severity() == Severity::Error)
+        .collect();
+    if !errors.is_empty() {
+        return Err(LoadError::Invalid(errors));
+    }
+
+    Ok(Catalogue::new(enemies).expect("validated ids are unique"))
+}
+
+/// Writes enemies out as a dataset that [`parse`] can read back.
+pub fn write(enemies: &[Enemy], format: Format) -> String {
+    match format {
+        Format::Json => serde_json::to_string_pretty(enemies).expect("enemies serialize to JSON"),
+        #[cfg(feature = "ron")]
+        Format::Ron => ron::ser::to_string_pretty(enemies, Default::default())
+            .expect("enemies serialize to RON"),
+        #[cfg(feature = "toml")]
+        Format::Toml => toml::to_string(&Table { enemies }).expect("enemies serialize to TOML"),
+    }
+}
@@ -1,3542 +1,5905 @@
 use crate::{
     biomes::Biome as B,
+    categories::Category as C,
     events::Event as E,
+    kinds::Kind as K,
     layers::Layer as L,
-    prelude::{Coins, Enemy, Rarity::*},
+    prelude::{Coins, Enemy, Rarity::*, Stats},
+    stages::Stage as S,
+    times::Time as T,
 };
 
+/// Every enemy in the game, built at compile time.
+///
+/// Bosses and mini-bosses are included, see [`Enemy::category`].
+pub static ENEMIES: &[Enemy] = &[
+    Enemy::new(
+        1,
+        "Blue Slime",
+        25,
+        2,
+        7,
+        0,
+        Coins::new(0, 0, 25),
+        &[B::Forest],
+        T::Any,
+        &[E::Slime],
+        &[L::Surface, L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        2,
+        "Demon Eye",
+        60,
+        2,
+        18,
+        20,
+        Coins::new(0, 0, 75),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        3,
+        "Zombie",
+        45,
+        6,
+        14,
+        50,
+        Coins::new(0, 0, 60),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        6,
+        "Eater of Souls",
+        40,
+        8,
+        22,
+        20,
+        Coins::new(0, 0, 90),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        7,
+        "Devourer",
+        100,
+        6,
+        31,
+        100,
+        Coins::new(0, 1, 40),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Any],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Worm],
+    ),
+    Enemy::new(
+        16,
+        "Mother Slime",
+        90,
+        7,
+        20,
+        0,
+        Coins::new(0, 0, 75),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        24,
+        "Fire Imp",
+        70,
+        16,
+        8,
+        20,
+        Coins::new(0, 3, 50),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Caster],
+    ),
+    Enemy::new(
+        26,
+        "Goblin Peon",
+        60,
+        4,
+        12,
+        20,
+        Coins::new(0, 1, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Goblin],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        27,
+        "Goblin Thief",
+        80,
+        6,
+        20,
+        20,
+        Coins::new(0, 2, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Goblin],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        28,
+        "Goblin Warrior",
+        110,
+        8,
+        24,
+        40,
+        Coins::new(0, 1, 50),
+        &[B::Any],
+        T::Any,
+        &[E::Goblin],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        29,
+        "Goblin Sorcerer",
+        40,
+        2,
+        12,
+        20,
+        Coins::new(0, 2, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Goblin],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid, K::Caster],
+    ),
+    Enemy::new(
+        31,
+        "Angry Bones",
+        80,
+        8,
+        26,
+        20,
+        Coins::new(0, 1, 30),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        32,
+        "Dark Caster",
+        50,
+        2,
+        20,
+        40,
+        Coins::new(0, 1, 40),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton, K::Caster],
+    ),
+    Enemy::new(
+        34,
+        "Cursed Skull",
+        40,
+        6,
+        35,
+        80,
+        Coins::new(0, 1, 50),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton, K::Flier],
+    ),
+    Enemy::new(
+        39,
+        "Bone Serpent",
+        300,
+        18,
+        30,
+        100,
+        Coins::new(0, 12, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton, K::Worm],
+    ),
+    Enemy::new(
+        42,
+        "Hornet",
+        48,
+        12,
+        26,
+        50,
+        Coins::new(0, 2, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        43,
+        "Man Eater",
+        110,
+        10,
+        35,
+        100,
+        Coins::new(0, 3, 50),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Plant],
+    ),
+    Enemy::new(
+        44,
+        "Undead Miner",
+        70,
+        9,
+        22,
+        50,
+        Coins::new(0, 2, 50),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        45,
+        "Tim",
+        200,
+        4,
+        20,
+        40,
+        Coins::new(0, 50, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        VeryRare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton, K::Caster],
+    ),
+    Enemy::new(
+        46,
+        "Bunny",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        47,
+        "Corrupt Bunny",
+        70,
+        4,
+        20,
+        0,
+        Coins::new(0, 5, 0),
+        &[B::Any, B::Corruption],
+        T::Night,
+        &[E::Blood],
+        &[L::Any],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        48,
+        "Harpy",
+        100,
+        8,
+        25,
+        30,
+        Coins::new(0, 3, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        49,
+        "Cave Bat",
+        16,
+        2,
+        13,
+        0,
+        Coins::new(0, 0, 90),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        51,
+        "Jungle Bat",
+        34,
+        4,
+        20,
+        0,
+        Coins::new(0, 0, 80),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        52,
+        "Doctor Bones",
+        500,
+        100,
+        50,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        53,
+        "The Groom",
+        200,
+        8,
+        14,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Graveyard],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        55,
+        "Goldfish",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Any],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer, K::Critter],
+    ),
+    Enemy::new(
+        56,
+        "Snatcher",
+        60,
+        10,
+        25,
+        100,
+        Coins::new(0, 0, 90),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Plant],
+    ),
+    Enemy::new(
+        57,
+        "Corrupt Goldfish",
+        100,
+        6,
+        30,
+        0,
+        Coins::new(0, 5, 0),
+        &[B::Any, B::Corruption],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        58,
+        "Piranha",
+        30,
+        2,
+        25,
+        0,
+        Coins::new(0, 0, 50),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        59,
+        "Lava Slime",
+        50,
+        10,
+        15,
+        0,
+        Coins::new(0, 1, 20),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        60,
+        "Hellbat",
+        46,
+        8,
+        35,
+        20,
+        Coins::new(0, 1, 20),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        61,
+        "Vulture",
+        40,
+        4,
+        15,
+        50,
+        Coins::new(0, 0, 60),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        62,
+        "Demon",
+        120,
+        8,
+        32,
+        20,
+        Coins::new(0, 3, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        63,
+        "Blue Jellyfish",
+        34,
+        4,
+        20,
+        0,
+        Coins::new(0, 1, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        64,
+        "Pink Jellyfish",
+        70,
+        4,
+        30,
+        0,
+        Coins::new(0, 1, 0),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        65,
+        "Shark",
+        300,
+        2,
+        40,
+        10,
+        Coins::new(0, 4, 0),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        66,
+        "Voodoo Demon",
+        140,
+        8,
+        32,
+        20,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        67,
+        "Crab",
+        40,
+        10,
+        20,
+        0,
+        Coins::new(0, 0, 60),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        69,
+        "Antlion",
+        45,
+        6,
+        10,
+        100,
+        Coins::new(0, 0, 60),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Shooter],
+    ),
+    Enemy::new(
+        71,
+        "Dungeon Slime",
+        150,
+        7,
+        30,
+        0,
+        Coins::new(0, 1, 50),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Any],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        73,
+        "Goblin Scout",
+        80,
+        6,
+        20,
+        20,
+        Coins::new(0, 2, 0),
+        &[B::Forest],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        74,
+        "Bird",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest, B::Snow, B::Hallow],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        75,
+        "Pixie",
+        150,
+        20,
+        55,
+        20,
+        Coins::new(0, 3, 50),
+        &[B::Hallow],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        77,
+        "Armored Skeleton",
+        260,
+        28,
+        60,
+        60,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        78,
+        "Mummy",
+        130,
+        16,
+        60,
+        50,
+        Coins::new(0, 6, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        79,
+        "Dark Mummy",
+        180,
+        18,
+        70,
+        50,
+        Coins::new(0, 7, 0),
+        &[B::Desert, B::Corruption],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        80,
+        "Light Mummy",
+        200,
+        18,
+        70,
+        50,
+        Coins::new(0, 7, 0),
+        &[B::Desert, B::Hallow],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        81,
+        "Corrupt Slime",
+        170,
+        20,
+        55,
+        30,
+        Coins::new(0, 4, 0),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        82,
+        "Wraith",
+        160,
+        16,
+        75,
+        40,
+        Coins::new(0, 5, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Ghost],
+    ),
+    Enemy::new(
+        83,
+        "Cursed Hammer",
+        200,
+        18,
+        80,
+        55,
+        Coins::new(0, 10, 0),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Construct],
+    ),
+    Enemy::new(
+        84,
+        "Enchanted Sword",
+        200,
+        18,
+        80,
+        55,
+        Coins::new(0, 10, 0),
+        &[B::Hallow],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Construct],
+    ),
+    Enemy::new(
+        85,
+        "Mimic",
+        500,
+        30,
+        80,
+        70,
+        Coins::new(10, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Any],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Mimic],
+    ),
+    Enemy::new(
+        86,
+        "Unicorn",
+        400,
+        30,
+        65,
+        30,
+        Coins::new(0, 10, 0),
+        &[B::Hallow],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        87,
+        "Wyvern",
+        4000,
+        20,
+        50,
+        100,
+        Coins::new(1, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Worm],
+    ),
+    Enemy::new(
+        93,
+        "Giant Bat",
+        100,
+        16,
+        70,
+        20,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        94,
+        "Corruptor",
+        230,
+        32,
+        50,
+        30,
+        Coins::new(0, 5, 0),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        95,
+        "Digger",
+        200,
+        20,
+        60,
+        100,
+        Coins::new(0, 3, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Worm],
+    ),
+    Enemy::new(
+        98,
+        "World Feeder",
+        500,
+        40,
+        80,
+        100,
+        Coins::new(0, 7, 0),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Worm],
+    ),
+    Enemy::new(
+        101,
+        "Clinger",
+        320,
+        30,
+        70,
+        100,
+        Coins::new(0, 6, 0),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Plant, K::Shooter],
+    ),
+    Enemy::new(
+        102,
+        "Angler Fish",
+        90,
+        22,
+        80,
+        50,
+        Coins::new(0, 5, 0),
+        &[B::Any, B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        103,
+        "Green Jellyfish",
+        120,
+        30,
+        50,
+        0,
+        Coins::new(0, 8, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        104,
+        "Werewolf",
+        350,
+        38,
+        70,
+        60,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        109,
+        "Clown",
+        800,
+        25,
+        50,
+        60,
+        Coins::new(1, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        110,
+        "Skeleton Archer",
+        210,
+        14,
+        55,
+        30,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Skeleton, K::Shooter],
+    ),
+    Enemy::new(
+        111,
+        "Goblin Archer",
+        80,
+        6,
+        20,
+        20,
+        Coins::new(0, 2, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Goblin],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        120,
+        "Chaos Elemental",
+        370,
+        30,
+        70,
+        10,
+        Coins::new(0, 6, 0),
+        &[B::Hallow],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Caster],
+    ),
+    Enemy::new(
+        121,
+        "Slimer",
+        60,
+        20,
+        45,
+        30,
+        Coins::new(0, 1, 0),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Slime, K::Flier],
+    ),
+    Enemy::new(
+        122,
+        "Gastropod",
+        220,
+        22,
+        60,
+        20,
+        Coins::new(0, 6, 0),
+        &[B::Hallow],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        133,
+        "Wandering Eye",
+        300,
+        20,
+        60,
+        50,
+        Coins::new(0, 5, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        137,
+        "Illuminant Bat",
+        200,
+        30,
+        75,
+        0,
+        Coins::new(0, 5, 0),
+        &[B::Hallow],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        138,
+        "Illuminant Slime",
+        180,
+        30,
+        70,
+        40,
+        Coins::new(0, 4, 0),
+        &[B::Hallow],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        140,
+        "Posessed Armor",
+        260,
+        28,
+        75,
+        60,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Construct],
+    ),
+    Enemy::new(
+        141,
+        "Toxic Sludge",
+        150,
+        18,
+        60,
+        30,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        143,
+        "Snowman Gangsta",
+        200,
+        20,
+        40,
+        60,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Legion],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        144,
+        "Mister Stabby",
+        240,
+        26,
+        50,
+        60,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Legion],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        145,
+        "Snow Balla",
+        220,
+        22,
+        40,
+        60,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Legion],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        147,
+        "Ice Slime",
+        30,
+        4,
+        12,
+        0,
+        Coins::new(0, 0, 50),
+        &[B::Snow],
+        T::Day,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        148,
+        "Penguin",
+        50,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        150,
+        "Ice Bat",
+        30,
+        6,
+        20,
+        20,
+        Coins::new(0, 2, 50),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        151,
+        "Lava Bat",
+        160,
+        16,
+        60,
+        20,
+        Coins::new(0, 4, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        152,
+        "Giant Flying Fox",
+        220,
+        24,
+        60,
+        20,
+        Coins::new(0, 4, 0),
+        &[B::Jungle],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        153,
+        "Giant Tortoise",
+        470,
+        30,
+        60,
+        100,
+        Coins::new(0, 5, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        154,
+        "Ice Tortoise",
+        400,
+        28,
+        60,
+        100,
+        Coins::new(0, 4, 50),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        155,
+        "Wolf",
+        300,
+        30,
+        70,
+        60,
+        Coins::new(0, 10, 0),
+        &[B::Snow],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        156,
+        "Red Devil",
+        600,
+        40,
+        80,
+        20,
+        Coins::new(0, 12, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        157,
+        "Arapaima",
+        200,
+        30,
+        60,
+        30,
+        Coins::new(0, 5, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        159,
+        "Vampire",
+        750,
+        24,
+        80,
+        40,
+        Coins::new(0, 50, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Bat],
+    ),
+    Enemy::new(
+        161,
+        "Frozen Zombie",
+        45,
+        6,
+        14,
+        50,
+        Coins::new(0, 0, 60),
+        &[B::Snow],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        162,
+        "Frankenstein",
+        350,
+        18,
+        80,
+        60,
+        Coins::new(0, 6, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        163,
+        "Black Recluse",
+        350,
+        40,
+        90,
+        50,
+        Coins::new(0, 5, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Spider],
+    ),
+    Enemy::new(
+        164,
+        "Wall Creeper",
+        80,
+        10,
+        30,
+        50,
+        Coins::new(0, 1, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Spider],
+    ),
+    Enemy::new(
+        166,
+        "Swamp Thing",
+        450,
+        26,
+        70,
+        60,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        167,
+        "Undead Viking",
+        70,
+        10,
+        36,
+        20,
+        Coins::new(0, 2, 0),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        168,
+        "Corrupt Penguin",
+        70,
+        4,
+        30,
+        0,
+        Coins::new(0, 5, 0),
+        &[B::Snow],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        169,
+        "Ice Elemental",
+        200,
+        20,
+        60,
+        40,
+        Coins::new(0, 15, 0),
+        &[B::Snow],
+        T::Night,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Construct, K::Caster],
+    ),
+    Enemy::new(
+        170,
+        "Pigron (Corrupt)",
+        210,
+        16,
+        70,
+        10,
+        Coins::new(0, 20, 0),
+        &[B::Snow, B::Corruption],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast, K::Flier],
+    ),
+    Enemy::new(
+        171,
+        "Pigron (Hallow)",
+        210,
+        16,
+        70,
+        10,
+        Coins::new(0, 20, 0),
+        &[B::Snow, B::Hallow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast, K::Flier],
+    ),
+    Enemy::new(
+        172,
+        "Rune Wizard",
+        600,
+        30,
+        80,
+        40,
+        Coins::new(0, 50, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        VeryRare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Caster],
+    ),
+    Enemy::new(
+        173,
+        "Crimera",
+        40,
+        8,
+        22,
+        20,
+        Coins::new(0, 0, 90),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        174,
+        "Herpling",
+        220,
+        26,
+        60,
+        0,
+        Coins::new(0, 4, 50),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        175,
+        "Angry Trapper",
+        300,
+        30,
+        80,
+        100,
+        Coins::new(0, 6, 50),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Plant],
+    ),
+    Enemy::new(
+        176,
+        "Moss Hornet",
+        220,
+        22,
+        60,
+        20,
+        Coins::new(0, 6, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        177,
+        "Derpling",
+        300,
+        26,
+        60,
+        20,
+        Coins::new(0, 5, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        179,
+        "Crimson Axe",
+        200,
+        18,
+        80,
+        55,
+        Coins::new(0, 10, 0),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Construct],
+    ),
+    Enemy::new(
+        181,
+        "Face Monster",
+        70,
+        10,
+        25,
+        40,
+        Coins::new(0, 2, 0),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        182,
+        "Floaty Gross",
+        240,
+        18,
+        50,
+        50,
+        Coins::new(0, 5, 0),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Ghost],
+    ),
+    Enemy::new(
+        183,
+        "Crimslime",
+        200,
+        26,
+        60,
+        30,
+        Coins::new(0, 4, 0),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        184,
+        "Spiked Ice Slime",
+        60,
+        8,
+        22,
+        0,
+        Coins::new(0, 2, 0),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime, K::Shooter],
+    ),
+    Enemy::new(
+        185,
+        "Snow Flinx",
+        70,
+        12,
+        20,
+        0,
+        Coins::new(0, 2, 0),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        195,
+        "Nymph",
+        300,
+        16,
+        35,
+        40,
+        Coins::new(2, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        VeryRare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        197,
+        "Armored Viking",
+        280,
+        28,
+        70,
+        40,
+        Coins::new(0, 5, 0),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        198,
+        "Lihzahrd",
+        400,
+        20,
+        70,
+        50,
+        Coins::new(0, 6, 50),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        201,
+        "Skeleton",
+        65,
+        12,
+        25,
+        30,
+        Coins::new(0, 1, 30),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        205,
+        "Moth",
+        1000,
+        28,
+        70,
+        40,
+        Coins::new(0, 6, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Rare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        206,
+        "Icy Merman",
+        280,
+        30,
+        80,
+        40,
+        Coins::new(0, 5, 0),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        212,
+        "Pirate Deckhand",
+        300,
+        17,
+        40,
+        40,
+        Coins::new(0, 7, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Pirate],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        213,
+        "Pirate Corsair",
+        450,
+        22,
+        50,
+        40,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Pirate],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        214,
+        "Pirate Deadeye",
+        225,
+        14,
+        40,
+        40,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Pirate],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        215,
+        "Pirate Crossbower",
+        350,
+        20,
+        40,
+        40,
+        Coins::new(0, 15, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Pirate],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        216,
+        "Pirate Captain",
+        3000,
+        30,
+        50,
+        70,
+        Coins::new(5, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Pirate],
+        &[L::Surface],
+        Rare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        217,
+        "Cochineal Beetle",
+        40,
+        10,
+        30,
+        0,
+        Coins::new(0, 0, 60),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        218,
+        "Cyan Beetle",
+        40,
+        10,
+        30,
+        0,
+        Coins::new(0, 0, 60),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        219,
+        "Lac Beetle",
+        40,
+        10,
+        30,
+        0,
+        Coins::new(0, 0, 60),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        220,
+        "Sea Snail",
+        40,
+        10,
+        30,
+        0,
+        Coins::new(0, 0, 60),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Any],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        221,
+        "Squid",
+        30,
+        2,
+        20,
+        10,
+        Coins::new(0, 1, 0),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Any],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        223,
+        "Raincoat Zombie",
+        50,
+        8,
+        14,
+        50,
+        Coins::new(0, 0, 70),
+        &[B::Any],
+        T::Night,
+        &[E::Rain],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        224,
+        "Flying Fish",
+        20,
+        4,
+        20,
+        0,
+        Coins::new(0, 3, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Rain],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Swimmer],
+    ),
+    Enemy::new(
+        225,
+        "Umbrella Slime",
+        35,
+        5,
+        25,
+        0,
+        Coins::new(0, 2, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Rain],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        226,
+        "Flying Snake",
+        260,
+        28,
+        70,
+        60,
+        Coins::new(0, 4, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        236,
+        "Jungle Creeper",
+        400,
+        28,
+        90,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Spider],
+    ),
+    Enemy::new(
+        239,
+        "Blood Crawler",
+        60,
+        8,
+        30,
+        50,
+        Coins::new(0, 1, 30),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Spider],
+    ),
+    Enemy::new(
+        241,
+        "Blood Feeder",
+        150,
+        20,
+        60,
+        0,
+        Coins::new(0, 5, 0),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        242,
+        "Blood Jelly",
+        150,
+        20,
+        70,
+        0,
+        Coins::new(0, 8, 0),
+        &[B::Crimson, B::Ocean],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        243,
+        "Ice Golem",
+        4000,
+        32,
+        60,
+        100,
+        Coins::new(1, 50, 0),
+        &[B::Snow],
+        T::Any,
+        &[E::Rain],
+        &[L::Surface],
+        Rare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Construct, K::Shooter],
+    ),
+    Enemy::new(
+        244,
+        "Rainbow Slime",
+        400,
+        26,
+        90,
+        30,
+        Coins::new(0, 20, 0),
+        &[B::Hallow],
+        T::Any,
+        &[E::Rain],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        250,
+        "Angry Nimbus",
+        300,
+        24,
+        40,
+        60,
+        Coins::new(0, 3, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Rain],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        251,
+        "Eyezor",
+        1000,
+        30,
+        60,
+        40,
+        Coins::new(0, 50, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie, K::Shooter],
+    ),
+    Enemy::new(
+        252,
+        "Parrot",
+        100,
+        12,
+        50,
+        10,
+        Coins::new(0, 5, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Pirate],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        253,
+        "Reaper",
+        700,
+        22,
+        60,
+        40,
+        Coins::new(0, 15, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Ghost],
+    ),
+    Enemy::new(
+        255,
+        "Spore Zombie",
+        220,
+        16,
+        25,
+        50,
+        Coins::new(0, 12, 0),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        256,
+        "Fungo Fish",
+        140,
+        20,
+        70,
+        30,
+        Coins::new(0, 12, 0),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        257,
+        "Anomura Fungus",
+        230,
+        24,
+        70,
+        30,
+        Coins::new(0, 13, 0),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        258,
+        "Mushi Ladybug",
+        220,
+        16,
+        70,
+        30,
+        Coins::new(0, 15, 0),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        259,
+        "Fungi Bulb",
+        90,
+        4,
+        50,
+        100,
+        Coins::new(0, 3, 50),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Plant],
+    ),
+    Enemy::new(
+        268,
+        "Ichor Sticker",
+        340,
+        20,
+        70,
+        20,
+        Coins::new(0, 4, 50),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        269,
+        "Rusty Armored Bones",
+        550,
+        34,
+        75,
+        30,
+        Coins::new(0, 10, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        273,
+        "Blue Armored Bones",
+        500,
+        50,
+        70,
+        30,
+        Coins::new(0, 10, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        277,
+        "Hell Armored Bones",
+        400,
+        32,
+        80,
+        30,
+        Coins::new(0, 10, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        282,
+        "Ragged Caster",
+        450,
+        28,
+        60,
+        40,
+        Coins::new(0, 15, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton, K::Caster],
+    ),
+    Enemy::new(
+        283,
+        "Necromancer",
+        450,
+        24,
+        60,
+        40,
+        Coins::new(0, 15, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton, K::Caster],
+    ),
+    Enemy::new(
+        285,
+        "Diabolist",
+        250,
+        10,
+        60,
+        40,
+        Coins::new(0, 15, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton, K::Caster],
+    ),
+    Enemy::new(
+        287,
+        "Bone Lee",
+        1000,
+        42,
+        80,
+        50,
+        Coins::new(0, 20, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        288,
+        "Dungeon Spirit",
+        200,
+        30,
+        70,
+        20,
+        Coins::new(0, 5, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Ghost],
+    ),
+    Enemy::new(
+        289,
+        "Giant Cursed Skull",
+        400,
+        20,
+        60,
+        80,
+        Coins::new(0, 1, 50),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton, K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        290,
+        "Paladin",
+        5000,
+        50,
+        100,
+        80,
+        Coins::new(5, 0, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Rare,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        291,
+        "Skeleton Sniper",
+        400,
+        28,
+        80,
+        30,
+        Coins::new(0, 10, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton, K::Shooter],
+    ),
+    Enemy::new(
+        292,
+        "Tactical Skeleton",
+        400,
+        28,
+        80,
+        30,
+        Coins::new(0, 10, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton, K::Shooter],
+    ),
+    Enemy::new(
+        293,
+        "Skeleton Commando",
+        400,
+        28,
+        80,
+        30,
+        Coins::new(0, 10, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Skeleton, K::Shooter],
+    ),
+    Enemy::new(
+        298,
+        "Cardinal",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest, B::Snow, B::Hallow],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        299,
+        "Squirrel",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        300,
+        "Mouse",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        301,
+        "Raven",
+        35,
+        2,
+        25,
+        0,
+        Coins::new(0, 0, 50),
+        &[B::Graveyard],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        304,
+        "Hoppin' Jack",
+        175,
+        20,
+        40,
+        50,
+        Coins::new(0, 5, 0),
+        &[B::Forest, B::Graveyard],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        305,
+        "Scarecrow",
+        500,
+        18,
+        70,
+        50,
+        Coins::new(0, 12, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Pumpkin],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        315,
+        "Headless Horseman",
+        5000,
+        40,
+        100,
+        80,
+        Coins::new(1, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Pumpkin],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        316,
+        "Ghost",
+        50,
+        4,
+        20,
+        20,
+        Coins::new(0, 0, 90),
+        &[B::Graveyard],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Ghost],
+    ),
+    Enemy::new(
+        325,
+        "Mourning Wood",
+        14000,
+        34,
+        70,
+        100,
+        Coins::new(1, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Pumpkin],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::MiniBoss,
+        &[K::Plant, K::Shooter],
+    ),
+    Enemy::new(
+        327,
+        "Pumpking",
+        26000,
+        40,
+        80,
+        100,
+        Coins::new(5, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Pumpkin],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::MiniBoss,
+        &[K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        326,
+        "Splinterling",
+        1200,
+        32,
+        70,
+        40,
+        Coins::new(0, 20, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Pumpkin],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Plant],
+    ),
+    Enemy::new(
+        329,
+        "Hellhound",
+        1800,
+        38,
+        80,
+        60,
+        Coins::new(0, 30, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Pumpkin],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        330,
+        "Poltergeist",
+        1250,
+        44,
+        80,
+        60,
+        Coins::new(0, 45, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Pumpkin],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Ghost],
+    ),
+    Enemy::new(
+        338,
+        "Zombie Elf",
+        600,
+        18,
+        80,
+        50,
+        Coins::new(0, 12, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        342,
+        "Gingerbread Man",
+        750,
+        26,
+        80,
+        50,
+        Coins::new(0, 18, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        343,
+        "Yeti",
+        3500,
+        50,
+        90,
+        80,
+        Coins::new(0, 30, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Beast, K::Shooter],
+    ),
+    Enemy::new(
+        344,
+        "Everscream",
+        13000,
+        38,
+        80,
+        100,
+        Coins::new(1, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::MiniBoss,
+        &[K::Plant, K::Shooter],
+    ),
+    Enemy::new(
+        345,
+        "Ice Queen",
+        34000,
+        38,
+        90,
+        100,
+        Coins::new(5, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::MiniBoss,
+        &[K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        346,
+        "Santa-NK1",
+        18000,
+        56,
+        100,
+        100,
+        Coins::new(1, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::MiniBoss,
+        &[K::Construct, K::Shooter],
+    ),
+    Enemy::new(
+        347,
+        "Elf Copter",
+        1200,
+        28,
+        80,
+        30,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        348,
+        "Nutcracker",
+        1800,
+        26,
+        80,
+        60,
+        Coins::new(0, 15, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid, K::Construct],
+    ),
+    Enemy::new(
+        350,
+        "Elf Archer",
+        900,
+        30,
+        80,
+        50,
+        Coins::new(0, 9, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        351,
+        "Krampus",
+        2500,
+        40,
+        100,
+        70,
+        Coins::new(0, 30, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        352,
+        "Flocko",
+        450,
+        8,
+        70,
+        40,
+        Coins::new(0, 5, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Frost],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        355,
+        "Firefly",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        356,
+        "Butterfly",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        357,
+        "Worm",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Worm, K::Critter],
+    ),
+    Enemy::new(
+        358,
+        "Lightning Bug",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Hallow],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        359,
+        "Snail",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        360,
+        "Glowing Snail",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        361,
+        "Frog",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        362,
+        "Duck",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        366,
+        "Scorpion",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        375,
+        "Truffle Worm",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        VeryRare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Worm, K::Critter],
+    ),
+    Enemy::new(
+        377,
+        "Grasshopper",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        379,
+        "Cultist Archer",
+        200,
+        14,
+        40,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        381,
+        "Brain Scrambler",
+        350,
+        25,
+        80,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        382,
+        "Ray Gunner",
+        350,
+        25,
+        80,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        383,
+        "Martian Officer",
+        300,
+        50,
+        80,
+        80,
+        Coins::new(0, 12, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        385,
+        "Gray Grunt",
+        750,
+        30,
+        80,
+        60,
+        Coins::new(0, 6, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        368,
+        "Martian Engineer",
+        400,
+        34,
+        80,
+        50,
+        Coins::new(0, 12, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        388,
+        "Martian Drone",
+        300,
+        16,
+        100,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier, K::Construct],
+    ),
+    Enemy::new(
+        389,
+        "Gigazapper",
+        600,
+        16,
+        80,
+        50,
+        Coins::new(0, 12, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        391,
+        "Scutlix",
+        600,
+        30,
+        80,
+        50,
+        Coins::new(0, 12, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Beast, K::Shooter],
+    ),
+    Enemy::new(
+        392,
+        "Martian Saucer",
+        17000,
+        100,
+        100,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Uncommon,
+        S::PostGolem,
+        C::MiniBoss,
+        &[K::Flier, K::Construct, K::Shooter],
+    ),
+    Enemy::new(
+        399,
+        "Martian Probe",
+        500,
+        5,
+        0,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Rare,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier, K::Construct],
+    ),
+    Enemy::new(
+        402,
+        "Milkyway Weaver",
+        1200,
+        0,
+        70,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Stardust],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Worm],
+    ),
+    Enemy::new(
+        405,
+        "Star Cell",
+        300,
+        50,
+        100,
+        70,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Stardust],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        407,
+        "Flow Invader",
+        1500,
+        38,
+        100,
+        20,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Stardust],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        409,
+        "Twinkle Popper",
+        800,
+        40,
+        100,
+        50,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Stardust],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Shooter],
+    ),
+    Enemy::new(
+        411,
+        "Stargazer",
+        700,
+        34,
+        100,
+        80,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Stardust],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid, K::Caster],
+    ),
+    Enemy::new(
+        412,
+        "Crawltipede",
+        10000,
+        0,
+        100,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Solar],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Worm],
+    ),
+    Enemy::new(
+        415,
+        "Drakomire",
+        800,
+        32,
+        100,
+        70,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Solar],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        417,
+        "Sroller",
+        700,
+        34,
+        100,
+        60,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Solar],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        418,
+        "Corite",
+        600,
+        26,
+        100,
+        90,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Solar],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        419,
+        "Selenian",
+        800,
+        30,
+        100,
+        70,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Solar],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        420,
+        "Nebula Floater",
+        1300,
+        20,
+        100,
+        80,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Nebula],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        421,
+        "Brain Suckler",
+        330,
+        34,
+        100,
+        20,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Nebula],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        423,
+        "Evolution Beast",
+        850,
+        46,
+        100,
+        60,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Nebula],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        424,
+        "Predictor",
+        700,
+        30,
+        100,
+        50,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Nebula],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid, K::Caster],
+    ),
+    Enemy::new(
+        425,
+        "Storm Diver",
+        800,
+        40,
+        100,
+        40,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Vortex],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid, K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        426,
+        "Alien Queen",
+        1000,
+        44,
+        100,
+        80,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Vortex],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        429,
+        "Vortexian",
+        700,
+        34,
+        100,
+        50,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar, E::Vortex],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        438,
+        "Lunatic Devotee",
+        400,
+        0,
+        0,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        453,
+        "Skeleton Merchant",
+        250,
+        30,
+        0,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        454,
+        "Phantasm Dragon",
+        10000,
+        30,
+        50,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier, K::Worm],
+    ),
+    Enemy::new(
+        460,
+        "Butcher",
+        700,
+        30,
+        60,
+        60,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Common,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        461,
+        "Creature from the Deep",
+        400,
+        22,
+        50,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        462,
+        "Fritz",
+        270,
+        14,
+        40,
+        50,
+        Coins::new(0, 6, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        463,
+        "Nailhead",
+        4000,
+        34,
+        80,
+        70,
+        Coins::new(0, 30, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        464,
+        "Vicious Bunny",
+        75,
+        5,
+        30,
+        0,
+        Coins::new(0, 5, 0),
+        &[B::Any],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        465,
+        "Vicious Goldfish",
+        110,
+        7,
+        40,
+        0,
+        Coins::new(0, 5, 0),
+        &[B::Crimson],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface, L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        466,
+        "Psycho",
+        550,
+        40,
+        80,
+        60,
+        Coins::new(0, 15, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        467,
+        "Deadly Sphere",
+        350,
+        80,
+        60,
+        20,
+        Coins::new(0, 9, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Flier, K::Construct],
+    ),
+    Enemy::new(
+        468,
+        "Dr. Man Fly",
+        500,
+        24,
+        50,
+        60,
+        Coins::new(0, 13, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Uncommon,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        469,
+        "The Possessed",
+        600,
+        28,
+        60,
+        50,
+        Coins::new(0, 13, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        470,
+        "Vicious Penguin",
+        75,
+        5,
+        30,
+        0,
+        Coins::new(0, 5, 0),
+        &[B::Snow],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        471,
+        "Goblin Warlock",
+        2000,
+        26,
+        40,
+        20,
+        Coins::new(0, 50, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Goblin],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::MiniBoss,
+        &[K::Humanoid, K::Caster],
+    ),
+    Enemy::new(
+        472,
+        "Shadowflame Apparition",
+        180,
+        18,
+        50,
+        60,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Goblin],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Flier, K::Ghost],
+    ),
+    Enemy::new(
+        473,
+        "Corrupt Mimic",
+        3500,
+        34,
+        90,
+        100,
+        Coins::new(3, 0, 0),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        VeryRare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Mimic],
+    ),
+    Enemy::new(
+        474,
+        "Crimson Mimic",
+        3500,
+        34,
+        90,
+        100,
+        Coins::new(3, 0, 0),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        VeryRare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Mimic],
+    ),
+    Enemy::new(
+        475,
+        "Hallowed Mimic",
+        3500,
+        34,
+        90,
+        100,
+        Coins::new(3, 0, 0),
+        &[B::Hallow],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        VeryRare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Mimic],
+    ),
+    Enemy::new(
+        477,
+        "Mothron",
+        6000,
+        30,
+        90,
+        80,
+        Coins::new(5, 0, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        VeryRare,
+        S::PostPlantera,
+        C::MiniBoss,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        479,
+        "Baby Mothron",
+        700,
+        14,
+        40,
+        20,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Day,
+        &[E::Eclipse],
+        &[L::Surface],
+        Rare,
+        S::PostPlantera,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        480,
+        "Medusa",
+        400,
+        20,
+        60,
+        60,
+        Coins::new(0, 10, 0),
+        &[B::Margranite],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Rare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Caster],
+    ),
+    Enemy::new(
+        481,
+        "Hoplite",
+        70,
+        10,
+        40,
+        20,
+        Coins::new(0, 3, 0),
+        &[B::Margranite],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton, K::Shooter],
+    ),
+    Enemy::new(
+        482,
+        "Granite Golem",
+        110,
+        18,
+        30,
+        40,
+        Coins::new(0, 5, 0),
+        &[B::Margranite],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Construct],
+    ),
+    Enemy::new(
+        483,
+        "Granite Elemental",
+        40,
+        8,
+        30,
+        40,
+        Coins::new(0, 10, 0),
+        &[B::Margranite],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Construct],
+    ),
+    Enemy::new(
+        484,
+        "Enchanted Nightcrawler",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Worm, K::Critter],
+    ),
+    Enemy::new(
+        485,
+        "Grubby",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        486,
+        "Sluggy",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        487,
+        "Buggy",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        489,
+        "Blood Zombie",
+        75,
+        8,
+        26,
+        50,
+        Coins::new(0, 1, 50),
+        &[B::Any],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        490,
+        "Drippler",
+        50,
+        14,
+        30,
+        50,
+        Coins::new(0, 1, 50),
+        &[B::Any],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        491,
+        "Flying Dutchman",
+        8000,
+        20,
+        50,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Pirate],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::MiniBoss,
+        &[K::Flier, K::Construct, K::Shooter],
+    ),
+    Enemy::new(
+        494,
+        "Crawdad",
+        50,
+        6,
+        25,
+        20,
+        Coins::new(0, 1, 20),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        496,
+        "Giant Shelly",
+        50,
+        12,
+        25,
+        50,
+        Coins::new(0, 1, 20),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        498,
+        "Salamander (Any)",
+        65,
+        10,
+        25,
+        30,
+        Coins::new(0, 1, 20),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast, K::Shooter],
+    ),
+    Enemy::new(
+        510,
+        "Dune Splicer",
+        500,
+        34,
+        60,
+        100,
+        Coins::new(0, 7, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Worm],
+    ),
+    Enemy::new(
+        520,
+        "Martian Walker",
+        2000,
+        40,
+        100,
+        80,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Martian],
+        &[L::Surface],
+        Uncommon,
+        S::PostGolem,
+        C::Regular,
+        &[K::Construct, K::Shooter],
+    ),
+    Enemy::new(
+        524,
+        "Ghoul",
+        180,
+        26,
+        70,
+        50,
+        Coins::new(0, 5, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        525,
+        "Vile Ghoul",
+        250,
+        30,
+        70,
+        50,
+        Coins::new(0, 6, 50),
+        &[B::Desert, B::Corruption],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        526,
+        "Tainted Ghoul",
+        220,
+        32,
+        70,
+        50,
+        Coins::new(0, 6, 50),
+        &[B::Desert, B::Crimson],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        527,
+        "Dreamer Ghoul",
+        300,
+        32,
+        70,
+        50,
+        Coins::new(0, 7, 50),
+        &[B::Desert, B::Hallow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        528,
+        "Lamia (Any)",
+        350,
+        28,
+        70,
+        50,
+        Coins::new(0, 6, 0),
+        &[B::Desert, B::Hallow],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        530,
+        "Sand Poacher",
+        320,
+        24,
+        60,
+        40,
+        Coins::new(0, 6, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Spider],
+    ),
+    Enemy::new(
+        532,
+        "Basilisk",
+        270,
+        34,
+        70,
+        40,
+        Coins::new(0, 8, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        533,
+        "Desert Spirit",
+        220,
+        20,
+        60,
+        50,
+        Coins::new(0, 12, 0),
+        &[B::Desert, B::Crimson, B::Corruption],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Ghost, K::Caster],
+    ),
+    Enemy::new(
+        534,
+        "Tortured Soul",
+        400,
+        20,
+        50,
+        30,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Ghost],
+    ),
+    Enemy::new(
+        536,
+        "The Bride",
+        200,
+        8,
+        14,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Graveyard],
+        T::Night,
+        &[E::Blood],
+        &[L::Surface],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        537,
+        "Sand Slime",
+        50,
+        5,
+        25,
+        0,
+        Coins::new(0, 0, 75),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        541,
+        "Sand Elemental",
+        5000,
+        30,
+        60,
+        100,
+        Coins::new(1, 50, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Rare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Construct, K::Caster],
+    ),
+    Enemy::new(
+        542,
+        "Sand Shark",
+        360,
+        20,
+        60,
+        50,
+        Coins::new(0, 4, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        543,
+        "Bone Biter",
+        380,
+        24,
+        60,
+        50,
+        Coins::new(0, 4, 0),
+        &[B::Desert, B::Corruption],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        544,
+        "Flesh Reaver",
+        400,
+        22,
+        60,
+        50,
+        Coins::new(0, 4, 0),
+        &[B::Desert, B::Crimson],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        545,
+        "Crystal Thresher",
+        450,
+        26,
+        60,
+        50,
+        Coins::new(0, 4, 0),
+        &[B::Desert, B::Hallow],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        546,
+        "Angry Tumbler",
+        50,
+        6,
+        20,
+        10,
+        Coins::new(0, 1, 0),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Plant],
+    ),
+    Enemy::new(
+        551,
+        "Betsy",
+        50000,
+        38,
+        80,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Uncommon,
+        S::PostGolem,
+        C::MiniBoss,
+        &[K::Beast, K::Flier],
+    ),
+    Enemy::new(
+        552,
+        "Etherian Goblin",
+        30,
+        14,
+        20,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        553,
+        "Etherian Goblin Bomber",
+        50,
+        16,
+        24,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        558,
+        "Etherian Wyvern",
+        60,
+        4,
+        30,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        561,
+        "Etherian Javelin Thrower",
+        60,
+        18,
+        30,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Humanoid, K::Shooter],
+    ),
+    Enemy::new(
+        564,
+        "Dark Mage",
+        4000,
+        38,
+        50,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::MiniBoss,
+        &[K::Humanoid, K::Caster],
+    ),
+    Enemy::new(
+        568,
+        "Wither Beast",
+        500,
+        30,
+        50,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        570,
+        "Drakin",
+        900,
+        30,
+        80,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Beast, K::Shooter],
+    ),
+    Enemy::new(
+        572,
+        "Kobold",
+        260,
+        26,
+        60,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        574,
+        "Kobold Glider",
+        170,
+        16,
+        60,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Humanoid, K::Flier],
+    ),
+    Enemy::new(
+        576,
+        "Ogre",
+        13000,
+        40,
+        80,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Surface],
+        Uncommon,
+        S::Hardmode,
+        C::MiniBoss,
+        &[K::Humanoid],
+    ),
+    Enemy::new(
+        578,
+        "Etherian Lightning Bug",
+        500,
+        36,
+        70,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::OldOnes],
+        &[L::Any],
+        Common,
+        S::PostGolem,
+        C::Regular,
+        &[K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        580,
+        "Antlion Charger",
+        80,
+        10,
+        30,
+        50,
+        Coins::new(0, 0, 80),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        581,
+        "Antlion Swarmer",
+        60,
+        8,
+        30,
+        0,
+        Coins::new(0, 0, 90),
+        &[B::Desert],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier],
+    ),
+    Enemy::new(
+        583,
+        "Fairy (Any)",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        586,
+        "Zombie Merman",
+        400,
+        20,
+        60,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Ocean],
+        T::Night,
+        &[E::Blood],
+        &[L::Any],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        587,
+        "Wandering Eye Fish",
+        300,
+        18,
+        55,
+        50,
+        Coins::new(0, 10, 0),
+        &[B::Ocean],
+        T::Night,
+        &[E::Blood],
+        &[L::Any],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Beast],
+    ),
+    Enemy::new(
+        602,
+        "Seagull",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Ocean],
+        T::Day,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        604,
+        "Ladybug",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        606,
+        "Maggot",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Graveyard],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        610,
+        "Rat",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Graveyard],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        611,
+        "Owl",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        615,
+        "Dolphin",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Any],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer, K::Critter],
+    ),
+    Enemy::new(
+        616,
+        "Turtle",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest],
+        T::Day,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        617,
+        "Jungle Turtle",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        620,
+        "Hemogoblin Shark",
+        5000,
+        30,
+        80,
+        50,
+        Coins::new(0, 75, 0),
+        &[B::Ocean],
+        T::Night,
+        &[E::Blood],
+        &[L::Any],
+        Rare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer],
+    ),
+    Enemy::new(
+        621,
+        "Blood Eel",
+        6000,
+        30,
+        60,
+        100,
+        Coins::new(0, 75, 0),
+        &[B::Ocean],
+        T::Night,
+        &[E::Blood],
+        &[L::Any],
+        Rare,
+        S::Hardmode,
+        C::Regular,
+        &[K::Swimmer, K::Worm],
+    ),
+    Enemy::new(
+        624,
+        "Gnome",
+        25,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        625,
+        "Sea Turtle",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        626,
+        "Seahorse",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Any],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Swimmer, K::Critter],
+    ),
+    Enemy::new(
+        628,
+        "Angry Dandelion",
+        50,
+        0,
+        20,
+        0,
+        Coins::new(0, 0, 50),
+        &[B::Forest],
+        T::Any,
+        &[E::Wind],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Plant, K::Shooter],
+    ),
+    Enemy::new(
+        630,
+        "Blood Mummy",
+        180,
+        18,
+        60,
+        50,
+        Coins::new(0, 7, 0),
+        &[B::Desert, B::Crimson],
+        T::Any,
+        &[],
+        &[L::Surface, L::Underground, L::Caverns],
+        Common,
+        S::Hardmode,
+        C::Regular,
+        &[K::Zombie],
+    ),
+    Enemy::new(
+        631,
+        "Rock Golem",
+        1000,
+        35,
+        60,
+        100,
+        Coins::new(2, 50, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Construct],
+    ),
+    Enemy::new(
+        634,
+        "Spore Bat",
+        16,
+        2,
+        18,
+        0,
+        Coins::new(0, 0, 90),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Bat],
+    ),
+    Enemy::new(
+        635,
+        "Spore Skeleton",
+        60,
+        8,
+        30,
+        30,
+        Coins::new(0, 1, 0),
+        &[B::Mushroom],
+        T::Any,
+        &[],
+        &[L::Any],
+        Common,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Skeleton],
+    ),
+    Enemy::new(
+        639,
+        "Gem Squirrel (Any)",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        646,
+        "Gem Bunny (Any)",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Caverns],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        653,
+        "Hell Butterfly",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        654,
+        "Lavafly",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        655,
+        "Magma Snail",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        661,
+        "Prismatic Lacewing",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Hallow],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        669,
+        "Stinkbug",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Forest],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Uncommon,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Critter],
+    ),
+    Enemy::new(
+        676,
+        "Shimmer Slime",
+        80,
+        5,
+        28,
+        0,
+        Coins::new(0, 1, 50),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        VeryRare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        677,
+        "Faeling",
+        5,
+        0,
+        0,
+        0,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Rare,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Flier, K::Critter],
+    ),
+    Enemy::new(
+        1004,
+        "Pinky",
+        150,
+        5,
+        45,
+        50,
+        Coins::new(1, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Slime],
+        &[L::Surface, L::Underground, L::Caverns],
+        Legendary,
+        S::PreHardmode,
+        C::Regular,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        50,
+        "King Slime",
+        2000,
+        10,
+        40,
+        100,
+        Coins::new(1, 0, 0),
+        &[B::Forest],
+        T::Any,
+        &[E::Slime],
+        &[L::Surface],
+        Legendary,
+        S::PreHardmode,
+        C::Boss,
+        &[K::Slime],
+    ),
+    Enemy::new(
+        4,
+        "Eye of Cthulhu",
+        2800,
+        12,
+        15,
+        100,
+        Coins::new(3, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::PreHardmode,
+        C::Boss,
+        &[K::Flier],
+    )
+    .with_expert(Stats {
+        life: 3640,
+        defence: 12,
+        damage: 30,
+        knockback_resist: 100,
+        coins: Coins::new(7, 50, 0),
+    })
+    .with_master(Stats {
+        life: 4641,
+        defence: 12,
+        damage: 45,
+        knockback_resist: 100,
+        coins: Coins::new(7, 50, 0),
+    }),
+    Enemy::new(
+        13,
+        "Eater of Worlds",
+        10050,
+        2,
+        22,
+        100,
+        Coins::new(2, 0, 0),
+        &[B::Corruption],
+        T::Any,
+        &[],
+        &[L::Any],
+        Legendary,
+        S::PreHardmode,
+        C::Boss,
+        &[K::Worm],
+    )
+    .with_expert(Stats {
+        life: 14100,
+        defence: 2,
+        damage: 44,
+        knockback_resist: 100,
+        coins: Coins::new(5, 0, 0),
+    })
+    .with_master(Stats {
+        life: 17980,
+        defence: 2,
+        damage: 66,
+        knockback_resist: 100,
+        coins: Coins::new(5, 0, 0),
+    }),
+    Enemy::new(
+        266,
+        "Brain of Cthulhu",
+        1250,
+        14,
+        30,
+        100,
+        Coins::new(2, 50, 0),
+        &[B::Crimson],
+        T::Any,
+        &[],
+        &[L::Any],
+        Legendary,
+        S::PreHardmode,
+        C::Boss,
+        &[K::Flier],
+    )
+    .with_expert(Stats {
+        life: 2500,
+        defence: 14,
+        damage: 60,
+        knockback_resist: 100,
+        coins: Coins::new(6, 25, 0),
+    })
+    .with_master(Stats {
+        life: 3187,
+        defence: 14,
+        damage: 90,
+        knockback_resist: 100,
+        coins: Coins::new(6, 25, 0),
+    }),
+    Enemy::new(
+        222,
+        "Queen Bee",
+        3400,
+        8,
+        30,
+        100,
+        Coins::new(5, 0, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Legendary,
+        S::PreHardmode,
+        C::Boss,
+        &[K::Flier, K::Shooter],
+    ),
+    Enemy::new(
+        35,
+        "Skeletron",
+        4400,
+        10,
+        32,
+        100,
+        Coins::new(5, 0, 0),
+        &[B::Dungeon],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::PreHardmode,
+        C::Boss,
+        &[K::Skeleton, K::Flier],
+    )
+    .with_expert(Stats {
+        life: 8800,
+        defence: 10,
+        damage: 64,
+        knockback_resist: 100,
+        coins: Coins::new(12, 50, 0),
+    })
+    .with_master(Stats {
+        life: 11220,
+        defence: 10,
+        damage: 96,
+        knockback_resist: 100,
+        coins: Coins::new(12, 50, 0),
+    }),
+    Enemy::new(
+        668,
+        "Deerclops",
+        7000,
+        10,
+        20,
+        100,
+        Coins::new(5, 0, 0),
+        &[B::Snow],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::PreHardmode,
+        C::Boss,
+        &[K::Beast, K::Caster],
+    ),
+    Enemy::new(
+        113,
+        "Wall of Flesh",
+        8000,
+        12,
+        50,
+        100,
+        Coins::new(8, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[],
+        &[L::Underworld],
+        Legendary,
+        S::PreHardmode,
+        C::Boss,
+        &[K::Shooter],
+    ),
+    Enemy::new(
+        657,
+        "Queen Slime",
+        18000,
+        26,
+        60,
+        100,
+        Coins::new(10, 0, 0),
+        &[B::Hallow],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::Hardmode,
+        C::Boss,
+        &[K::Slime, K::Flier],
+    ),
+    Enemy::new(
+        134,
+        "The Destroyer",
+        80000,
+        0,
+        70,
+        100,
+        Coins::new(12, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::Hardmode,
+        C::Boss,
+        &[K::Worm, K::Construct],
+    )
+    .with_expert(Stats {
+        life: 120000,
+        defence: 0,
+        damage: 140,
+        knockback_resist: 100,
+        coins: Coins::new(30, 0, 0),
+    })
+    .with_master(Stats {
+        life: 153000,
+        defence: 0,
+        damage: 210,
+        knockback_resist: 100,
+        coins: Coins::new(30, 0, 0),
+    }),
+    Enemy::new(
+        125,
+        "Retinazer",
+        20000,
+        10,
+        50,
+        100,
+        Coins::new(6, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::Hardmode,
+        C::Boss,
+        &[K::Flier, K::Construct, K::Shooter],
+    ),
+    Enemy::new(
+        126,
+        "Spazmatism",
+        23000,
+        10,
+        50,
+        100,
+        Coins::new(6, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::Hardmode,
+        C::Boss,
+        &[K::Flier, K::Construct, K::Shooter],
+    ),
+    Enemy::new(
+        127,
+        "Skeletron Prime",
+        28000,
+        24,
+        50,
+        100,
+        Coins::new(12, 0, 0),
+        &[B::Any],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::Hardmode,
+        C::Boss,
+        &[K::Skeleton, K::Flier, K::Construct],
+    ),
+    Enemy::new(
+        262,
+        "Plantera",
+        30000,
+        14,
+        50,
+        100,
+        Coins::new(15, 0, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Legendary,
+        S::Hardmode,
+        C::Boss,
+        &[K::Plant, K::Shooter],
+    ),
+    Enemy::new(
+        245,
+        "Golem",
+        39000,
+        26,
+        70,
+        100,
+        Coins::new(15, 0, 0),
+        &[B::Jungle],
+        T::Any,
+        &[],
+        &[L::Underground, L::Caverns],
+        Legendary,
+        S::PostPlantera,
+        C::Boss,
+        &[K::Construct, K::Shooter],
+    ),
+    Enemy::new(
+        370,
+        "Duke Fishron",
+        50000,
+        50,
+        70,
+        100,
+        Coins::new(25, 0, 0),
+        &[B::Ocean],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::Hardmode,
+        C::Boss,
+        &[K::Flier, K::Swimmer],
+    )
+    .with_expert(Stats {
+        life: 60000,
+        defence: 50,
+        damage: 140,
+        knockback_resist: 100,
+        coins: Coins::new(62, 50, 0),
+    })
+    .with_master(Stats {
+        life: 76500,
+        defence: 50,
+        damage: 210,
+        knockback_resist: 100,
+        coins: Coins::new(62, 50, 0),
+    }),
+    Enemy::new(
+        636,
+        "Empress of Light",
+        70000,
+        50,
+        80,
+        100,
+        Coins::new(25, 0, 0),
+        &[B::Hallow],
+        T::Night,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::PostPlantera,
+        C::Boss,
+        &[K::Flier, K::Caster],
+    ),
+    Enemy::new(
+        439,
+        "Lunatic Cultist",
+        32000,
+        42,
+        50,
+        100,
+        Coins::new(10, 0, 0),
+        &[B::Dungeon],
+        T::Any,
+        &[],
+        &[L::Surface],
+        Legendary,
+        S::PostGolem,
+        C::Boss,
+        &[K::Humanoid, K::Caster],
+    ),
+    Enemy::new(
+        398,
+        "Moon Lord",
+        145000,
+        70,
+        0,
+        100,
+        Coins::new(0, 0, 0),
+        &[B::Any],
+        T::Any,
+        &[E::Lunar],
+        &[L::Surface],
+        Legendary,
+        S::PostGolem,
+        C::Boss,
+        &[K::Caster],
+    )
+    .with_expert(Stats {
+        life: 217500,
+        defence: 70,
+        damage: 0,
+        knockback_resist: 100,
+        coins: Coins::new(0, 0, 0),
+    })
+    .with_master(Stats {
+        life: 277312,
+        defence: 70,
+        damage: 0,
+        knockback_resist: 100,
+        coins: Coins::new(0, 0, 0),
+    }),
+];
+
+/// An owned copy of [`ENEMIES`] without the bosses, i.e. the pool of the
+/// classic game. Names and attribute lists stay borrowed, so only the outer
+/// slice is allocated.
 pub fn list_enemies() -> Box<[Enemy]> {
-    Box::new([
-        Enemy::new(
-            1,
-            "Blue Slime",
-            25,
-            2,
-            Coins::new(0, 0, 25),
-            vec![B::Forest],
-            vec![E::Slime],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            2,
-            "Demon Eye",
-            60,
-            2,
-            Coins::new(0, 0, 75),
-            vec![B::Any, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            3,
-            "Zombie",
-            45,
-            6,
-            Coins::new(0, 0, 60),
-            vec![B::Any, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            6,
-            "Eater of Souls",
-            40,
-            8,
-            Coins::new(0, 0, 90),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            7,
-            "Devourer",
-            100,
-            6,
-            Coins::new(0, 1, 40),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Any],
-            Uncommon,
-        ),
-        Enemy::new(
-            16,
-            "Mother Slime",
-            90,
-            7,
-            Coins::new(0, 0, 75),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            24,
-            "Fire Imp",
-            70,
-            16,
-            Coins::new(0, 3, 50),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Common,
-        ),
-        Enemy::new(
-            26,
-            "Goblin Peon",
-            60,
-            4,
-            Coins::new(0, 1, 0),
-            vec![B::Any],
-            vec![E::Goblin],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            27,
-            "Goblin Thief",
-            80,
-            6,
-            Coins::new(0, 2, 0),
-            vec![B::Any],
-            vec![E::Goblin],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            28,
-            "Goblin Warrior",
-            110,
-            8,
-            Coins::new(0, 1, 50),
-            vec![B::Any],
-            vec![E::Goblin],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            29,
-            "Goblin Sorcerer",
-            40,
-            2,
-            Coins::new(0, 2, 0),
-            vec![],
-            vec![E::Goblin],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            31,
-            "Angry Bones",
-            80,
-            8,
-            Coins::new(0, 1, 30),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            32,
-            "Dark Caster",
-            50,
-            2,
-            Coins::new(0, 1, 40),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            34,
-            "Cursed Skull",
-            40,
-            6,
-            Coins::new(0, 1, 50),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            39,
-            "Bone Serpent",
-            300,
-            18,
-            Coins::new(0, 12, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Uncommon,
-        ),
-        Enemy::new(
-            42,
-            "Hornet",
-            48,
-            12,
-            Coins::new(0, 2, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            43,
-            "Man Eater",
-            110,
-            10,
-            Coins::new(0, 3, 50),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            44,
-            "Undead Miner",
-            70,
-            9,
-            Coins::new(0, 2, 50),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            45,
-            "Tim",
-            200,
-            4,
-            Coins::new(0, 50, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            46,
-            "Bunny",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            47,
-            "Corrupt Bunny",
-            70,
-            4,
-            Coins::new(0, 5, 0),
-            vec![B::Any, B::Corruption, B::Night],
-            vec![E::Blood],
-            vec![L::Any],
-            Uncommon,
-        ),
-        Enemy::new(
-            48,
-            "Harpy",
-            100,
-            8,
-            Coins::new(0, 3, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            49,
-            "Cave Bat",
-            16,
-            2,
-            Coins::new(0, 0, 90),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            51,
-            "Jungle Bat",
-            34,
-            4,
-            Coins::new(0, 0, 80),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            52,
-            "Doctor Bones",
-            500,
-            100,
-            Coins::new(0, 10, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Rare,
-        ),
-        Enemy::new(
-            53,
-            "The Groom",
-            200,
-            8,
-            Coins::new(0, 10, 0),
-            vec![B::Graveyard, B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            55,
-            "Goldfish",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Any],
-            Uncommon,
-        ),
-        Enemy::new(
-            56,
-            "Snatcher",
-            60,
-            10,
-            Coins::new(0, 0, 90),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            57,
-            "Corrupt Goldfish",
-            100,
-            6,
-            Coins::new(0, 5, 0),
-            vec![B::Any, B::Corruption, B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            58,
-            "Piranha",
-            30,
-            2,
-            Coins::new(0, 0, 50),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            59,
-            "Lava Slime",
-            50,
-            10,
-            Coins::new(0, 1, 20),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Common,
-        ),
-        Enemy::new(
-            60,
-            "Hellbat",
-            46,
-            8,
-            Coins::new(0, 1, 20),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Common,
-        ),
-        Enemy::new(
-            61,
-            "Vulture",
-            40,
-            4,
-            Coins::new(0, 0, 60),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            62,
-            "Demon",
-            120,
-            8,
-            Coins::new(0, 3, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Common,
-        ),
-        Enemy::new(
-            63,
-            "Blue Jellyfish",
-            34,
-            4,
-            Coins::new(0, 1, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            64,
-            "Pink Jellyfish",
-            70,
-            4,
-            Coins::new(0, 1, 0),
-            vec![B::Ocean],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            65,
-            "Shark",
-            300,
-            2,
-            Coins::new(0, 4, 0),
-            vec![B::Ocean],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            66,
-            "Voodoo Demon",
-            140,
-            8,
-            Coins::new(0, 10, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Uncommon,
-        ),
-        Enemy::new(
-            67,
-            "Crab",
-            40,
-            10,
-            Coins::new(0, 0, 60),
-            vec![B::Ocean],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            69,
-            "Antlion",
-            45,
-            6,
-            Coins::new(0, 0, 60),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            71,
-            "Dungeon Slime",
-            150,
-            7,
-            Coins::new(0, 1, 50),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Any],
-            Uncommon,
-        ),
-        Enemy::new(
-            73,
-            "Goblin Scout",
-            80,
-            6,
-            Coins::new(0, 2, 0),
-            vec![B::Forest],
-            vec![],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            74,
-            "Bird",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest, B::Snow, B::Hallow],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            75,
-            "Pixie",
-            150,
-            20,
-            Coins::new(0, 3, 50),
-            vec![B::Hallow],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            77,
-            "Armored Skeleton",
-            260,
-            28,
-            Coins::new(0, 4, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            78,
-            "Mummy",
-            130,
-            16,
-            Coins::new(0, 6, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            79,
-            "Dark Mummy",
-            180,
-            18,
-            Coins::new(0, 7, 0),
-            vec![B::Desert, B::Corruption],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            80,
-            "Light Mummy",
-            200,
-            18,
-            Coins::new(0, 7, 0),
-            vec![B::Desert, B::Hallow],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            81,
-            "Corrupt Slime",
-            170,
-            20,
-            Coins::new(0, 4, 0),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            82,
-            "Wraith",
-            160,
-            16,
-            Coins::new(0, 5, 0),
-            vec![B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            83,
-            "Cursed Hammer",
-            200,
-            18,
-            Coins::new(0, 10, 0),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            84,
-            "Enchanted Sword",
-            200,
-            18,
-            Coins::new(0, 10, 0),
-            vec![B::Hallow],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            85,
-            "Mimic",
-            500,
-            30,
-            Coins::new(10, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Any],
-            Rare,
-        ),
-        Enemy::new(
-            86,
-            "Unicorn",
-            400,
-            30,
-            Coins::new(0, 10, 0),
-            vec![B::Hallow],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            87,
-            "Wyvern",
-            4000,
-            20,
-            Coins::new(1, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            93,
-            "Giant Bat",
-            100,
-            16,
-            Coins::new(0, 4, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            94,
-            "Corruptor",
-            230,
-            32,
-            Coins::new(0, 5, 0),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            95,
-            "Digger",
-            200,
-            20,
-            Coins::new(0, 3, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            98,
-            "World Feeder",
-            500,
-            40,
-            Coins::new(0, 7, 0),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            101,
-            "Clinger",
-            320,
-            30,
-            Coins::new(0, 6, 0),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            102,
-            "Angler Fish",
-            90,
-            22,
-            Coins::new(0, 5, 0),
-            vec![B::Any, B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            103,
-            "Green Jellyfish",
-            120,
-            30,
-            Coins::new(0, 8, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            104,
-            "Werewolf",
-            350,
-            38,
-            Coins::new(0, 10, 0),
-            vec![B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            109,
-            "Clown",
-            800,
-            25,
-            Coins::new(1, 0, 0),
-            vec![B::Any, B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            110,
-            "Skeleton Archer",
-            210,
-            14,
-            Coins::new(0, 4, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            111,
-            "Goblin Archer",
-            80,
-            6,
-            Coins::new(0, 2, 0),
-            vec![B::Any],
-            vec![E::Goblin],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            120,
-            "Chaos Elemental",
-            370,
-            30,
-            Coins::new(0, 6, 0),
-            vec![B::Hallow],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            121,
-            "Slimer",
-            60,
-            20,
-            Coins::new(0, 1, 0),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            122,
-            "Gastropod",
-            220,
-            22,
-            Coins::new(0, 6, 0),
-            vec![B::Hallow, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            133,
-            "Wandering Eye",
-            300,
-            20,
-            Coins::new(0, 5, 0),
-            vec![B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            137,
-            "Illuminant Bat",
-            200,
-            30,
-            Coins::new(0, 5, 0),
-            vec![B::Hallow],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            138,
-            "Illuminant Slime",
-            180,
-            30,
-            Coins::new(0, 4, 0),
-            vec![B::Hallow],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            140,
-            "Posessed Armor",
-            260,
-            28,
-            Coins::new(0, 4, 0),
-            vec![B::Any, B::Night],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            141,
-            "Toxic Sludge",
-            150,
-            18,
-            Coins::new(0, 4, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            143,
-            "Snowman Gangsta",
-            200,
-            20,
-            Coins::new(0, 4, 0),
-            vec![B::Any],
-            vec![E::Legion],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            144,
-            "Mister Stabby",
-            240,
-            26,
-            Coins::new(0, 4, 0),
-            vec![B::Any],
-            vec![E::Legion],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            145,
-            "Snow Balla",
-            220,
-            22,
-            Coins::new(0, 4, 0),
-            vec![B::Any],
-            vec![E::Legion],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            147,
-            "Ice Slime",
-            30,
-            4,
-            Coins::new(0, 0, 50),
-            vec![B::Snow, B::Day],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            148,
-            "Penguin",
-            50,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Snow],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            150,
-            "Ice Bat",
-            30,
-            6,
-            Coins::new(0, 2, 50),
-            vec![B::Snow],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            151,
-            "Lava Bat",
-            160,
-            16,
-            Coins::new(0, 4, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Common,
-        ),
-        Enemy::new(
-            152,
-            "Giant Flying Fox",
-            220,
-            24,
-            Coins::new(0, 4, 0),
-            vec![B::Jungle, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            153,
-            "Giant Tortoise",
-            470,
-            30,
-            Coins::new(0, 5, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            154,
-            "Ice Tortoise",
-            400,
-            28,
-            Coins::new(0, 4, 50),
-            vec![B::Snow],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            155,
-            "Wolf",
-            300,
-            30,
-            Coins::new(0, 10, 0),
-            vec![B::Snow, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            156,
-            "Red Devil",
-            600,
-            40,
-            Coins::new(0, 12, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Uncommon,
-        ),
-        Enemy::new(
-            157,
-            "Arapaima",
-            200,
-            30,
-            Coins::new(0, 5, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            159,
-            "Vampire",
-            750,
-            24,
-            Coins::new(0, 50, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            161,
-            "Frozen Zombie",
-            45,
-            6,
-            Coins::new(0, 0, 60),
-            vec![B::Snow, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            162,
-            "Frankenstein",
-            350,
-            18,
-            Coins::new(0, 6, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            163,
-            "Black Recluse",
-            350,
-            40,
-            Coins::new(0, 5, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            164,
-            "Wall Creeper",
-            80,
-            10,
-            Coins::new(0, 1, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            166,
-            "Swamp Thing",
-            450,
-            26,
-            Coins::new(0, 10, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            167,
-            "Undead Viking",
-            70,
-            10,
-            Coins::new(0, 2, 0),
-            vec![B::Snow],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            168,
-            "Corrupt Penguin",
-            70,
-            4,
-            Coins::new(0, 5, 0),
-            vec![B::Snow, B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            169,
-            "Ice Elemental",
-            200,
-            20,
-            Coins::new(0, 15, 0),
-            vec![B::Snow, B::Night],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            170,
-            "Pigron (Corrupt)",
-            210,
-            16,
-            Coins::new(0, 20, 0),
-            vec![B::Snow, B::Corruption],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            171,
-            "Pigron (Hallow)",
-            210,
-            16,
-            Coins::new(0, 20, 0),
-            vec![B::Snow, B::Hallow],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            172,
-            "Rune Wizard",
-            600,
-            30,
-            Coins::new(0, 50, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            173,
-            "Crimera",
-            40,
-            8,
-            Coins::new(0, 0, 90),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            174,
-            "Herpling",
-            220,
-            26,
-            Coins::new(0, 4, 50),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            175,
-            "Angry Trapper",
-            300,
-            30,
-            Coins::new(0, 6, 50),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            176,
-            "Moss Hornet",
-            220,
-            22,
-            Coins::new(0, 6, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            177,
-            "Derpling",
-            300,
-            26,
-            Coins::new(0, 5, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            179,
-            "Crimson Axe",
-            200,
-            18,
-            Coins::new(0, 10, 0),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            181,
-            "Face Monster",
-            70,
-            10,
-            Coins::new(0, 2, 0),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            182,
-            "Floaty Gross",
-            240,
-            18,
-            Coins::new(0, 5, 0),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            183,
-            "Crimslime",
-            200,
-            26,
-            Coins::new(0, 4, 0),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            184,
-            "Spiked Ice Slime",
-            60,
-            8,
-            Coins::new(0, 2, 0),
-            vec![B::Snow],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            185,
-            "Snow Flinx",
-            70,
-            12,
-            Coins::new(0, 2, 0),
-            vec![B::Snow],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            195,
-            "Nymph",
-            300,
-            16,
-            Coins::new(2, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            197,
-            "Armored Viking",
-            280,
-            28,
-            Coins::new(0, 5, 0),
-            vec![B::Snow],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            198,
-            "Lihzahrd",
-            400,
-            20,
-            Coins::new(0, 6, 50),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            201,
-            "Skeleton",
-            65,
-            12,
-            Coins::new(0, 1, 30),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            205,
-            "Moth",
-            1000,
-            28,
-            Coins::new(0, 6, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            206,
-            "Icy Merman",
-            280,
-            30,
-            Coins::new(0, 5, 0),
-            vec![B::Snow],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            212,
-            "Pirate Deckhand",
-            300,
-            17,
-            Coins::new(0, 7, 0),
-            vec![B::Any],
-            vec![E::Pirate],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            213,
-            "Pirate Corsair",
-            450,
-            22,
-            Coins::new(0, 10, 0),
-            vec![B::Any],
-            vec![E::Pirate],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            214,
-            "Pirate Deadeye",
-            225,
-            14,
-            Coins::new(0, 10, 0),
-            vec![B::Any],
-            vec![E::Pirate],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            215,
-            "Pirate Crossbower",
-            350,
-            20,
-            Coins::new(0, 15, 0),
-            vec![B::Any],
-            vec![E::Pirate],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            216,
-            "Pirate Captain",
-            3000,
-            30,
-            Coins::new(5, 0, 0),
-            vec![B::Any],
-            vec![E::Pirate],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            217,
-            "Cochineal Beetle",
-            40,
-            10,
-            Coins::new(0, 0, 60),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            218,
-            "Cyan Beetle",
-            40,
-            10,
-            Coins::new(0, 0, 60),
-            vec![B::Snow],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            219,
-            "Lac Beetle",
-            40,
-            10,
-            Coins::new(0, 0, 60),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            220,
-            "Sea Snail",
-            40,
-            10,
-            Coins::new(0, 0, 60),
-            vec![B::Ocean],
-            vec![],
-            vec![L::Any],
-            Rare,
-        ),
-        Enemy::new(
-            221,
-            "Squid",
-            30,
-            2,
-            Coins::new(0, 1, 0),
-            vec![B::Ocean],
-            vec![],
-            vec![L::Any],
-            Uncommon,
-        ),
-        Enemy::new(
-            223,
-            "Raincoat Zombie",
-            50,
-            8,
-            Coins::new(0, 0, 70),
-            vec![B::Any, B::Night],
-            vec![E::Rain],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            224,
-            "Flying Fish",
-            20,
-            4,
-            Coins::new(0, 3, 0),
-            vec![B::Any],
-            vec![E::Rain],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            225,
-            "Umbrella Slime",
-            35,
-            5,
-            Coins::new(0, 2, 0),
-            vec![B::Any],
-            vec![E::Rain],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            226,
-            "Flying Snake",
-            260,
-            28,
-            Coins::new(0, 4, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            236,
-            "Jungle Creeper",
-            400,
-            28,
-            Coins::new(0, 10, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            239,
-            "Blood Crawler",
-            60,
-            8,
-            Coins::new(0, 1, 30),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            241,
-            "Blood Feeder",
-            150,
-            20,
-            Coins::new(0, 5, 0),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            242,
-            "Blood Jelly",
-            150,
-            20,
-            Coins::new(0, 8, 0),
-            vec![B::Crimson, B::Ocean],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            243,
-            "Ice Golem",
-            4000,
-            32,
-            Coins::new(1, 50, 0),
-            vec![B::Snow],
-            vec![E::Rain],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            244,
-            "Rainbow Slime",
-            400,
-            26,
-            Coins::new(0, 20, 0),
-            vec![B::Hallow],
-            vec![E::Rain],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            250,
-            "Angry Nimbus",
-            300,
-            24,
-            Coins::new(0, 3, 0),
-            vec![B::Any],
-            vec![E::Rain],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            251,
-            "Eyezor",
-            1000,
-            30,
-            Coins::new(0, 50, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            252,
-            "Parrot",
-            100,
-            12,
-            Coins::new(0, 5, 0),
-            vec![B::Any],
-            vec![E::Pirate],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            253,
-            "Reaper",
-            700,
-            22,
-            Coins::new(0, 15, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            255,
-            "Spore Zombie",
-            220,
-            16,
-            Coins::new(0, 12, 0),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            256,
-            "Fungo Fish",
-            140,
-            20,
-            Coins::new(0, 12, 0),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            257,
-            "Anomura Fungus",
-            230,
-            24,
-            Coins::new(0, 13, 0),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            258,
-            "Mushi Ladybug",
-            220,
-            16,
-            Coins::new(0, 15, 0),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            258,
-            "Fungi Bulb",
-            90,
-            4,
-            Coins::new(0, 3, 50),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            268,
-            "Ichor Sticker",
-            340,
-            20,
-            Coins::new(0, 4, 50),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            269,
-            "Rusty Armored Bones",
-            550,
-            34,
-            Coins::new(0, 10, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            273,
-            "Blue Armored Bones",
-            500,
-            50,
-            Coins::new(0, 10, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            277,
-            "Hell Armored Bones",
-            400,
-            32,
-            Coins::new(0, 10, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            282,
-            "Ragged Caster",
-            450,
-            28,
-            Coins::new(0, 15, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            283,
-            "Necromancer",
-            450,
-            24,
-            Coins::new(0, 15, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            285,
-            "Diabolist",
-            250,
-            10,
-            Coins::new(0, 15, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            287,
-            "Bone Lee",
-            1000,
-            42,
-            Coins::new(0, 20, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            288,
-            "Dungeon Spirit",
-            200,
-            30,
-            Coins::new(0, 5, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            289,
-            "Giant Cursed Skull",
-            400,
-            20,
-            Coins::new(0, 1, 50),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            290,
-            "Paladin",
-            5000,
-            50,
-            Coins::new(5, 0, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            291,
-            "Skeleton Sniper",
-            400,
-            28,
-            Coins::new(0, 10, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            292,
-            "Tactical Skeleton",
-            400,
-            28,
-            Coins::new(0, 10, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            293,
-            "Skeleton Commando",
-            400,
-            28,
-            Coins::new(0, 10, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            298,
-            "Cardinal",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest, B::Snow, B::Hallow],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            299,
-            "Squirrel",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            300,
-            "Mouse",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            301,
-            "Raven",
-            35,
-            2,
-            Coins::new(0, 0, 50),
-            vec![B::Graveyard],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            304,
-            "Hoppin' Jack",
-            175,
-            20,
-            Coins::new(0, 5, 0),
-            vec![B::Forest, B::Night, B::Graveyard],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            305,
-            "Scarecrow",
-            500,
-            18,
-            Coins::new(0, 12, 0),
-            vec![B::Night],
-            vec![E::Pumpkin],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            315,
-            "Headless Horseman",
-            5000,
-            40,
-            Coins::new(1, 0, 0),
-            vec![B::Night],
-            vec![E::Pumpkin],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            316,
-            "Ghost",
-            50,
-            4,
-            Coins::new(0, 0, 90),
-            vec![B::Graveyard],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            325,
-            "Mourning Wood",
-            14000,
-            34,
-            Coins::new(1, 0, 0),
-            vec![B::Night],
-            vec![E::Pumpkin],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            327,
-            "Pumpking",
-            26000,
-            40,
-            Coins::new(5, 0, 0),
-            vec![B::Night],
-            vec![E::Pumpkin],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            326,
-            "Splinterling",
-            1200,
-            32,
-            Coins::new(0, 20, 0),
-            vec![B::Night],
-            vec![E::Pumpkin],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            329,
-            "Hellhound",
-            1800,
-            38,
-            Coins::new(0, 30, 0),
-            vec![B::Night],
-            vec![E::Pumpkin],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            330,
-            "Poltergeist",
-            1250,
-            44,
-            Coins::new(0, 45, 0),
-            vec![B::Night],
-            vec![E::Pumpkin],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            338,
-            "Zombie Elf",
-            600,
-            18,
-            Coins::new(0, 12, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            342,
-            "Gingerbread Man",
-            750,
-            26,
-            Coins::new(0, 18, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            343,
-            "Yeti",
-            3500,
-            50,
-            Coins::new(0, 30, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            344,
-            "Everscream",
-            13000,
-            38,
-            Coins::new(1, 0, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            345,
-            "Ice Queen",
-            34000,
-            38,
-            Coins::new(5, 0, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            346,
-            "Santa-NK1",
-            18000,
-            56,
-            Coins::new(1, 0, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            347,
-            "Elf Copter",
-            1200,
-            28,
-            Coins::new(0, 10, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            348,
-            "Nutcracker",
-            1800,
-            26,
-            Coins::new(0, 15, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            350,
-            "Elf Archer",
-            900,
-            30,
-            Coins::new(0, 9, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            351,
-            "Krampus",
-            2500,
-            40,
-            Coins::new(0, 30, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            352,
-            "Flocko",
-            450,
-            8,
-            Coins::new(0, 5, 0),
-            vec![B::Night],
-            vec![E::Frost],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            355,
-            "Firefly",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            356,
-            "Butterfly",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            357,
-            "Worm",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            358,
-            "Lightning Bug",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Hallow, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            359,
-            "Snail",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            360,
-            "Glowing Snail",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Uncommon,
-        ),
-        Enemy::new(
-            361,
-            "Frog",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            362,
-            "Duck",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            366,
-            "Scorpion",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            375,
-            "Truffle Worm",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Rare,
-        ),
-        Enemy::new(
-            377,
-            "Grasshopper",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            379,
-            "Cultist Archer",
-            200,
-            14,
-            Coins::new(0, 10, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            381,
-            "Brain Scrambler",
-            350,
-            25,
-            Coins::new(0, 10, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            382,
-            "Ray Gunner",
-            350,
-            25,
-            Coins::new(0, 10, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            383,
-            "Martian Officer",
-            300,
-            50,
-            Coins::new(0, 12, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            385,
-            "Gray Grunt",
-            750,
-            30,
-            Coins::new(0, 6, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            368,
-            "Martian Engineer",
-            400,
-            34,
-            Coins::new(0, 12, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            388,
-            "Martian Drone",
-            300,
-            16,
-            Coins::new(0, 10, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            389,
-            "Gigazapper",
-            600,
-            16,
-            Coins::new(0, 12, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            391,
-            "Scutlix",
-            600,
-            30,
-            Coins::new(0, 12, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            392,
-            "Martian Saucer",
-            17000,
-            100,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            399,
-            "Martian Probe",
-            500,
-            5,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            402,
-            "Milkyway Weaver",
-            1200,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Stardust],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            405,
-            "Star Cell",
-            300,
-            50,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Stardust],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            407,
-            "Flow Invader",
-            1500,
-            38,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Stardust],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            409,
-            "Twinkle Popper",
-            800,
-            40,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Stardust],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            411,
-            "Stargazer",
-            700,
-            34,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Stardust],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            412,
-            "Crawltipede",
-            10000,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Solar],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            415,
-            "Drakomire",
-            800,
-            32,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Solar],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            417,
-            "Sroller",
-            700,
-            34,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Solar],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            418,
-            "Corite",
-            600,
-            26,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Solar],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            419,
-            "Selenian",
-            800,
-            30,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Solar],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            420,
-            "Nebula Floater",
-            1300,
-            20,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Nebula],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            421,
-            "Brain Suckler",
-            330,
-            34,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Nebula],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            423,
-            "Evolution Beast",
-            850,
-            46,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Nebula],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            424,
-            "Predictor",
-            700,
-            30,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Nebula],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            425,
-            "Storm Diver",
-            800,
-            40,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Vortex],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            426,
-            "Alien Queen",
-            1000,
-            44,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Vortex],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            429,
-            "Vortexian",
-            700,
-            34,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Lunar, E::Vortex],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            438,
-            "Lunatic Devotee",
-            400,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            453,
-            "Skeleton Merchant",
-            250,
-            30,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            454,
-            "Phantasm Dragon",
-            10000,
-            30,
-            Coins::new(0, 0, 0),
-            vec![B::Dungeon],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            460,
-            "Butcher",
-            700,
-            30,
-            Coins::new(0, 10, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            461,
-            "Creature from the Deep",
-            400,
-            22,
-            Coins::new(0, 10, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            462,
-            "Fritz",
-            270,
-            14,
-            Coins::new(0, 6, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            463,
-            "Nailhead",
-            4000,
-            34,
-            Coins::new(0, 30, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            464,
-            "Vicious Bunny",
-            75,
-            5,
-            Coins::new(0, 5, 0),
-            vec![B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            465,
-            "Vicious Goldfish",
-            110,
-            7,
-            Coins::new(0, 5, 0),
-            vec![B::Crimson, B::Night],
-            vec![E::Blood],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            466,
-            "Psycho",
-            550,
-            40,
-            Coins::new(0, 15, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            467,
-            "Deadly Sphere",
-            350,
-            80,
-            Coins::new(0, 9, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            468,
-            "Dr. Man Fly",
-            500,
-            24,
-            Coins::new(0, 13, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            469,
-            "The Possessed",
-            600,
-            28,
-            Coins::new(0, 13, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            470,
-            "Vicious Penguin",
-            75,
-            5,
-            Coins::new(0, 5, 0),
-            vec![B::Snow, B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            471,
-            "Goblin Warlock",
-            2000,
-            26,
-            Coins::new(0, 50, 0),
-            vec![B::Any],
-            vec![E::Goblin],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            472,
-            "Shadowflame Apparition",
-            180,
-            18,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Goblin],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            473,
-            "Corrupt Mimic",
-            3500,
-            34,
-            Coins::new(3, 0, 0),
-            vec![B::Corruption],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            474,
-            "Crimson Mimic",
-            3500,
-            34,
-            Coins::new(3, 0, 0),
-            vec![B::Crimson],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            475,
-            "Hallowed Mimic",
-            3500,
-            34,
-            Coins::new(3, 0, 0),
-            vec![B::Hallow],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            477,
-            "Mothron",
-            6000,
-            30,
-            Coins::new(5, 0, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            479,
-            "Baby Mothron",
-            700,
-            14,
-            Coins::new(0, 0, 0),
-            vec![B::Day],
-            vec![E::Eclipse],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            480,
-            "Medusa",
-            400,
-            20,
-            Coins::new(0, 10, 0),
-            vec![B::Margranite],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            481,
-            "Hoplite",
-            70,
-            10,
-            Coins::new(0, 3, 0),
-            vec![B::Margranite],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            482,
-            "Granite Golem",
-            110,
-            18,
-            Coins::new(0, 5, 0),
-            vec![B::Margranite],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            483,
-            "Granite Elemental",
-            40,
-            8,
-            Coins::new(0, 10, 0),
-            vec![B::Margranite],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            484,
-            "Enchanted Nightcrawler",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any, B::Night],
-            vec![],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            485,
-            "Grubby",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            486,
-            "Sluggy",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            487,
-            "Buggy",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Uncommon,
-        ),
-        Enemy::new(
-            489,
-            "Blood Zombie",
-            75,
-            8,
-            Coins::new(0, 1, 50),
-            vec![B::Any, B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            490,
-            "Drippler",
-            50,
-            14,
-            Coins::new(0, 1, 50),
-            vec![B::Any, B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            491,
-            "Flying Dutchman",
-            8000,
-            20,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Pirate],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            494,
-            "Crawdad",
-            50,
-            6,
-            Coins::new(0, 1, 20),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            496,
-            "Giant Shelly",
-            50,
-            12,
-            Coins::new(0, 1, 20),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            498,
-            "Salamander (Any)",
-            65,
-            10,
-            Coins::new(0, 1, 20),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            510,
-            "Dune Splicer",
-            500,
-            34,
-            Coins::new(0, 7, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            520,
-            "Martian Walker",
-            2000,
-            40,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::Martian],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            524,
-            "Ghoul",
-            180,
-            26,
-            Coins::new(0, 5, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            525,
-            "Vile Ghoul",
-            250,
-            30,
-            Coins::new(0, 6, 50),
-            vec![B::Desert, B::Corruption],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            526,
-            "Tainted Ghoul",
-            220,
-            32,
-            Coins::new(0, 6, 50),
-            vec![B::Desert, B::Crimson],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            527,
-            "Dreamer Ghoul",
-            300,
-            32,
-            Coins::new(0, 7, 50),
-            vec![B::Desert, B::Hallow],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            528,
-            "Lamia (Any)",
-            350,
-            28,
-            Coins::new(0, 6, 0),
-            vec![B::Desert, B::Hallow],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            530,
-            "Sand Poacher",
-            320,
-            24,
-            Coins::new(0, 6, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            532,
-            "Basilisk",
-            270,
-            34,
-            Coins::new(0, 8, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            533,
-            "Desert Spirit",
-            220,
-            20,
-            Coins::new(0, 12, 0),
-            vec![B::Desert, B::Crimson, B::Corruption],
-            vec![],
-            vec![L::Underground],
-            Uncommon,
-        ),
-        Enemy::new(
-            534,
-            "Tortured Soul",
-            400,
-            20,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Uncommon,
-        ),
-        Enemy::new(
-            536,
-            "The Bride",
-            200,
-            8,
-            Coins::new(0, 10, 0),
-            vec![B::Graveyard, B::Night],
-            vec![E::Blood],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            537,
-            "Sand Slime",
-            50,
-            5,
-            Coins::new(0, 0, 75),
-            vec![B::Desert],
-            vec![],
-            vec![L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            541,
-            "Sand Elemental",
-            5000,
-            30,
-            Coins::new(1, 50, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            542,
-            "Sand Shark",
-            360,
-            20,
-            Coins::new(0, 4, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            543,
-            "Bone Biter",
-            380,
-            24,
-            Coins::new(0, 4, 0),
-            vec![B::Desert, B::Corruption],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            544,
-            "Flesh Reaver",
-            400,
-            22,
-            Coins::new(0, 4, 0),
-            vec![B::Desert, B::Crimson],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            545,
-            "Crystal Thresher",
-            450,
-            26,
-            Coins::new(0, 4, 0),
-            vec![B::Desert, B::Hallow],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            546,
-            "Angry Tumbler",
-            50,
-            6,
-            Coins::new(0, 1, 0),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            551,
-            "Betsy",
-            50000,
-            38,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            552,
-            "Etherian Goblin",
-            30,
-            14,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            553,
-            "Etherian Goblin Bomber",
-            50,
-            16,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            558,
-            "Etherian Wyvern",
-            60,
-            4,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            561,
-            "Etherian Javelin Thrower",
-            60,
-            18,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            564,
-            "Dark Mage",
-            4000,
-            38,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            568,
-            "Wither Beast",
-            500,
-            30,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            570,
-            "Drakin",
-            900,
-            30,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            572,
-            "Kobold",
-            260,
-            26,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            574,
-            "Kobold Glider",
-            170,
-            16,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            576,
-            "Ogre",
-            13000,
-            40,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            578,
-            "Etherian Lightning Bug",
-            500,
-            36,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![E::OldOnes],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            580,
-            "Antlion Charger",
-            80,
-            10,
-            Coins::new(0, 0, 80),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            581,
-            "Antlion Swarmer",
-            60,
-            8,
-            Coins::new(0, 0, 90),
-            vec![B::Desert],
-            vec![],
-            vec![L::Surface, L::Underground],
-            Common,
-        ),
-        Enemy::new(
-            583,
-            "Fairy (Any)",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any, B::Night],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            586,
-            "Zombie Merman",
-            400,
-            20,
-            Coins::new(0, 10, 0),
-            vec![B::Ocean, B::Night],
-            vec![E::Blood],
-            vec![L::Any],
-            Rare,
-        ),
-        Enemy::new(
-            587,
-            "Wandering Eye Fish",
-            300,
-            18,
-            Coins::new(0, 10, 0),
-            vec![B::Ocean, B::Night],
-            vec![E::Blood],
-            vec![L::Any],
-            Rare,
-        ),
-        Enemy::new(
-            602,
-            "Seagull",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Ocean, B::Day],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            604,
-            "Ladybug",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            606,
-            "Maggot",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Graveyard],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            610,
-            "Rat",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Graveyard],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            611,
-            "Owl",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest, B::Night],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            615,
-            "Dolphin",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Ocean],
-            vec![],
-            vec![L::Any],
-            Uncommon,
-        ),
-        Enemy::new(
-            616,
-            "Turtle",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest, B::Day],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            617,
-            "Jungle Turtle",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Jungle],
-            vec![],
-            vec![L::Surface],
-            Common,
-        ),
-        Enemy::new(
-            620,
-            "Hemogoblin Shark",
-            5000,
-            30,
-            Coins::new(0, 75, 0),
-            vec![B::Ocean, B::Night],
-            vec![E::Blood],
-            vec![L::Any],
-            Rare,
-        ),
-        Enemy::new(
-            621,
-            "Blood Eel",
-            6000,
-            30,
-            Coins::new(0, 75, 0),
-            vec![B::Ocean, B::Night],
-            vec![E::Blood],
-            vec![L::Any],
-            Rare,
-        ),
-        Enemy::new(
-            624,
-            "Gnome",
-            25,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            625,
-            "Sea Turtle",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Ocean],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            626,
-            "Seahorse",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Ocean],
-            vec![],
-            vec![L::Any],
-            Uncommon,
-        ),
-        Enemy::new(
-            628,
-            "Angry Dandelion",
-            50,
-            0,
-            Coins::new(0, 0, 50),
-            vec![B::Forest],
-            vec![E::Wind],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            630,
-            "Blood Mummy",
-            180,
-            18,
-            Coins::new(0, 7, 0),
-            vec![B::Desert, B::Crimson],
-            vec![],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Common,
-        ),
-        Enemy::new(
-            631,
-            "Rock Golem",
-            1000,
-            35,
-            Coins::new(2, 50, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            634,
-            "Spore Bat",
-            16,
-            2,
-            Coins::new(0, 0, 90),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            635,
-            "Spore Skeleton",
-            60,
-            8,
-            Coins::new(0, 1, 0),
-            vec![B::Mushroom],
-            vec![],
-            vec![L::Any],
-            Common,
-        ),
-        Enemy::new(
-            639,
-            "Gem Squirrel (Any)",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            646,
-            "Gem Bunny (Any)",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            653,
-            "Hell Butterfly",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Uncommon,
-        ),
-        Enemy::new(
-            654,
-            "Lavafly",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Uncommon,
-        ),
-        Enemy::new(
-            655,
-            "Magma Snail",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underworld],
-            Uncommon,
-        ),
-        Enemy::new(
-            661,
-            "Prismatic Lacewing",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Hallow, B::Night],
-            vec![],
-            vec![L::Surface],
-            Rare,
-        ),
-        Enemy::new(
-            669,
-            "Stinkbug",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Forest],
-            vec![],
-            vec![L::Surface],
-            Uncommon,
-        ),
-        Enemy::new(
-            676,
-            "Shimmer Slime",
-            80,
-            5,
-            Coins::new(0, 1, 50),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            677,
-            "Faeling",
-            5,
-            0,
-            Coins::new(0, 0, 0),
-            vec![B::Any],
-            vec![],
-            vec![L::Underground, L::Caverns],
-            Rare,
-        ),
-        Enemy::new(
-            1004,
-            "Pinky",
-            150,
-            5,
-            Coins::new(1, 0, 0),
-            vec![B::Any],
-            vec![E::Slime],
-            vec![L::Surface, L::Underground, L::Caverns],
-            Rare,
-        ),
-    ])
+    ENEMIES
+        .iter()
+        .filter(|enemy| enemy.category != C::Boss)
+        .cloned()
+        .collect()
 }
@@ -2,7 +2,9 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+use crate::prelude::Flag;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum Event {
     Goblin,
     Pirate,
@@ -50,3 +52,29 @@ impl Display for Event {
         write!(f, "{text}")
     }
 }
+
+impl Flag for Event {
+    const ALL: &'static [Self] = &[
+        Event::Goblin,
+        Event::Pirate,
+        Event::Rain,
+        Event::Slime,
+        Event::Wind,
+        Event::Martian,
+        Event::Eclipse,
+        Event::OldOnes,
+        Event::Blood,
+        Event::Lunar,
+        Event::Solar,
+        Event::Stardust,
+        Event::Vortex,
+        Event::Nebula,
+        Event::Legion,
+        Event::Frost,
+        Event::Pumpkin,
+    ];
+
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
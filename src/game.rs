@@ -0,0 +1,147 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    catalogue::Catalogue,
+    prelude::{Enemy, EnemyDiff},
+    rules::Rules,
+    search::normalize,
+    share,
+};
+
+/// A single guess and how it compared against the answer.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Guess {
+    pub enemy: Enemy,
+    pub diff: EnemyDiff,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Status {
+    InProgress,
+    Won,
+    Lost,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GuessError {
+    /// No enemy goes by the guessed name.
+    Unknown(String),
+    /// The enemy was already guessed this game.
+    Duplicate(String),
+    /// The game was already won or lost.
+    Finished,
+}
+
+impl Display for GuessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuessError::Unknown(name) => write!(f, "there is no enemy named {name:?}"),
+            GuessError::Duplicate(name) => write!(f, "{name} was already guessed"),
+            GuessError::Finished => write!(f, "the game is already over"),
+        }
+    }
+}
+
+impl std::error::Error for GuessError {}
+
+/// One round of the game: a secret answer and the guesses made against it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Game {
+    answer: Enemy,
+    max_guesses: usize,
+    #[serde(default)]
+    rules: Rules,
+    guesses: Vec<Guess>,
+}
+
+impl Game {
+    pub fn new(answer: Enemy, max_guesses: usize) -> Self {
+        Self::with_rules(answer, max_guesses, Rules::default())
+    }
+    pub fn with_rules(answer: Enemy, max_guesses: usize, rules: Rules) -> Self {
+        Self {
+            answer,
+            max_guesses,
+            rules,
+            guesses: vec![],
+        }
+    }
+    pub fn answer(&self) -> &Enemy {
+        &self.answer
+    }
+    pub fn max_guesses(&self) -> usize {
+        self.max_guesses
+    }
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+    /// Guesses in the order they were made.
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+    pub fn remaining(&self) -> usize {
+        self.max_guesses.saturating_sub(self.guesses.len())
+    }
+    pub fn status(&self) -> Status {
+        if self.guesses.iter().any(|guess| guess.diff.is_same()) {
+            Status::Won
+        } else if self.remaining() == 0 {
+            Status::Lost
+        } else {
+            Status::InProgress
+        }
+    }
+    /// The shareable emoji grid for this game, see [`share::grid`].
+    pub fn share(&self, puzzle: u32) -> String {
+        let diffs = self.guesses.iter().map(|guess| &guess.diff);
+        share::grid(puzzle, diffs, self.max_guesses)
+    }
+    /// Guesses the enemy with the given name, ignoring case, diacritics and
+    /// punctuation. Bosses aren't part of the classic game, see
+    /// [`Catalogue::regular`].
+    pub fn guess(&mut self, name: &str) -> Result<&EnemyDiff, GuessError> {
+        self.guess_in(Catalogue::regular(), name)
+    }
+    pub fn guess_enemy(&mut self, enemy: Enemy) -> Result<&EnemyDiff, GuessError> {
+        self.guess_enemy_in(Catalogue::regular(), enemy)
+    }
+    /// Like [`Game::guess`], for games played over another dataset.
+    pub fn guess_in(
+        &mut self,
+        catalogue: &Catalogue,
+        name: &str,
+    ) -> Result<&EnemyDiff, GuessError> {
+        let name = name.trim();
+        let key = normalize(name);
+        let enemy = catalogue
+            .enemies()
+            .iter()
+            .find(|enemy| normalize(&enemy.name) == key)
+            .cloned()
+            .ok_or_else(|| GuessError::Unknown(name.to_string()))?;
+
+        self.guess_enemy_in(catalogue, enemy)
+    }
+    pub fn guess_enemy_in(
+        &mut self,
+        catalogue: &Catalogue,
+        enemy: Enemy,
+    ) -> Result<&EnemyDiff, GuessError> {
+        if self.status() != Status::InProgress {
+            return Err(GuessError::Finished);
+        }
+        if catalogue.get(enemy.id) != Some(&enemy) {
+            return Err(GuessError::Unknown(enemy.name.into_owned()));
+        }
+        if self.guesses.iter().any(|guess| guess.enemy == enemy) {
+            return Err(GuessError::Duplicate(enemy.name.into_owned()));
+        }
+
+        let diff = enemy.diff_with(&self.answer, &self.rules);
+        self.guesses.push(Guess { enemy, diff });
+
+        Ok(&self.guesses[self.guesses.len() - 1].diff)
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::Flag;
+
+/// What sort of creature an enemy is. Most enemies are more than one kind,
+/// e.g. a Skeleton Archer is both a skeleton and a shooter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Kind {
+    Slime,
+    Zombie,
+    Skeleton,
+    Humanoid,
+    Beast,
+    Bat,
+    Flier,
+    Swimmer,
+    Worm,
+    Spider,
+    Plant,
+    Ghost,
+    Construct,
+    Caster,
+    Shooter,
+    Mimic,
+    Critter,
+}
+
+impl Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Kind::*;
+
+        let text = match self {
+            Slime => "Slime",
+            Zombie => "Zombie",
+            Skeleton => "Skeleton",
+            Humanoid => "Humanoid",
+            Beast => "Beast",
+            Bat => "Bat",
+            Flier => "Flier",
+            Swimmer => "Swimmer",
+            Worm => "Worm",
+            Spider => "Spider",
+            Plant => "Plant",
+            Ghost => "Ghost",
+            Construct => "Construct",
+            Caster => "Caster",
+            Shooter => "Shooter",
+            Mimic => "Mimic",
+            Critter => "Critter",
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+impl Flag for Kind {
+    const ALL: &'static [Self] = &[
+        Kind::Slime,
+        Kind::Zombie,
+        Kind::Skeleton,
+        Kind::Humanoid,
+        Kind::Beast,
+        Kind::Bat,
+        Kind::Flier,
+        Kind::Swimmer,
+        Kind::Worm,
+        Kind::Spider,
+        Kind::Plant,
+        Kind::Ghost,
+        Kind::Construct,
+        Kind::Caster,
+        Kind::Shooter,
+        Kind::Mimic,
+        Kind::Critter,
+    ];
+
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
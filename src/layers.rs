@@ -2,9 +2,9 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::Diff;
+use crate::prelude::{Diff, Flag};
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum Layer {
     Any,
     Surface,
@@ -29,4 +29,18 @@ impl Display for Layer {
     }
 }
 
+impl Flag for Layer {
+    const ALL: &'static [Self] = &[
+        Layer::Any,
+        Layer::Surface,
+        Layer::Underground,
+        Layer::Caverns,
+        Layer::Underworld,
+    ];
+
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
+
 pub type LayerDiff = Diff<Layer>;
@@ -3,6 +3,21 @@ pub mod enemies;
 pub mod biomes;
 pub mod layers;
 pub mod events;
+pub mod stages;
+pub mod categories;
+pub mod kinds;
+pub mod daily;
+pub mod game;
+pub mod solver;
+pub mod rating;
+pub mod share;
+pub mod search;
+pub mod catalogue;
+pub mod dataset;
+pub mod validate;
+pub mod rules;
+pub mod ambiguity;
+pub mod times;
 
 #[cfg(test)]
 mod test;
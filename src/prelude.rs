@@ -1,49 +1,276 @@
-use std::{cmp::Ordering, fmt::Display};
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    fmt::{Debug, Display},
+    iter::Sum,
+    marker::PhantomData,
+    ops::Add,
+    str::FromStr,
+};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{EnumAccess, IntoDeserializer, VariantAccess},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 
-use crate::{biomes::Biome, events::Event, layers::Layer};
+use crate::{
+    biomes::Biome,
+    categories::Category,
+    events::Event,
+    kinds::Kind,
+    layers::Layer,
+    rules::{CoinBasis, Column, Difficulty, Rules, Thresholds},
+    stages::Stage,
+    times::Time,
+};
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub struct Diff<T: Clone + PartialEq + Eq> {
-    pub right: Box<[T]>,
-    pub wrong: Box<[T]>,
-    pub missing: bool,
+/// An attribute with few enough values to be stored in a [`Set`].
+pub trait Flag: Copy + Eq + 'static {
+    /// Every value, in declaration order.
+    const ALL: &'static [Self];
+
+    /// Position of this value in [`Flag::ALL`].
+    fn index(self) -> u32;
+}
+
+/// A set of attribute values, stored as a bitmask so it never allocates.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Set<T> {
+    bits: u32,
+    marker: PhantomData<T>,
+}
+
+impl<T: Flag> Set<T> {
+    pub const fn new() -> Self {
+        Self {
+            bits: 0,
+            marker: PhantomData,
+        }
+    }
+    /// Returns whether the value was newly inserted.
+    pub fn insert(&mut self, value: T) -> bool {
+        let bit = 1 << value.index();
+        let inserted = self.bits & bit == 0;
+        self.bits |= bit;
+        inserted
+    }
+    /// Returns whether the value was present.
+    pub fn remove(&mut self, value: T) -> bool {
+        let bit = 1 << value.index();
+        let present = self.bits & bit != 0;
+        self.bits &= !bit;
+        present
+    }
+    pub fn contains(&self, value: T) -> bool {
+        self.bits & (1 << value.index()) != 0
+    }
+    pub fn len(&self) -> usize {
+        self.bits.count_ones() as usize
+    }
+    pub fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+    /// Values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & other.bits,
+            marker: PhantomData,
+        }
+    }
+    /// Values in this set but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            bits: self.bits & !other.bits,
+            marker: PhantomData,
+        }
+    }
+    /// The values in this set, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        T::ALL.iter().copied().filter(|value| self.contains(*value))
+    }
+}
+
+impl<T: Flag> Default for Set<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
-impl<T: Clone + PartialEq + Eq> Diff<T> {
+impl<T: Flag> FromIterator<T> for Set<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+impl<T: Flag + Debug> Debug for Set<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Flag + Serialize> Serialize for Set<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Flag + Deserialize<'de>> Deserialize<'de> for Set<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Vec::<T>::deserialize(deserializer)?.into_iter().collect())
+    }
+}
+
+/// How a guessed set of values compares to the answer's, as a whole.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Verdict {
+    /// The guess has exactly the answer's values.
+    Exact,
+    /// The guess shares some values with the answer, but not all of them.
+    Partial,
+    /// The guess and the answer have nothing in common.
+    None,
+}
+
+/// The result of comparing a list attribute. Both lists are treated as sets,
+/// so neither order nor repeated values make a difference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Diff<T: Flag> {
+    /// Guessed values the answer has too.
+    pub right: Set<T>,
+    /// Guessed values the answer doesn't have.
+    pub wrong: Set<T>,
+    /// How many of the answer's values weren't guessed. Only a count, so
+    /// the values themselves aren't given away.
+    pub missing: usize,
+    pub verdict: Verdict,
+}
+
+impl<T: Flag> Diff<T> {
     pub fn with(lhs: &[T], rhs: &[T]) -> Self {
-        let (right, wrong): (Vec<T>, Vec<T>) =
-            lhs.iter().cloned().partition(|item| rhs.contains(item));
-        let missing = !wrong.is_empty() || rhs.len() != lhs.len();
+        let guess: Set<T> = lhs.iter().copied().collect();
+        let answer: Set<T> = rhs.iter().copied().collect();
+        let right = guess.intersection(&answer);
+        let wrong = guess.difference(&answer);
+        let missing = answer.difference(&guess).len();
+        let verdict = if wrong.is_empty() && missing == 0 {
+            Verdict::Exact
+        } else if !right.is_empty() {
+            Verdict::Partial
+        } else {
+            Verdict::None
+        };
 
         Self {
-            right: right.into_boxed_slice(),
-            wrong: wrong.into_boxed_slice(),
+            right,
+            wrong,
             missing,
+            verdict,
         }
     }
+    pub fn is_exact(&self) -> bool {
+        self.verdict == Verdict::Exact
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+/// An amount of money, as the game's four coin denominations.
+///
+/// Coins compare by their total value, so `1 gold` equals `100 silver`.
+/// They serialize as that total in copper.
+#[derive(Debug, Clone)]
 pub struct Coins {
+    pub platinum: u8,
     pub gold: u8,
     pub silver: u8,
     pub copper: u8,
 }
 
 impl Coins {
-    pub fn new(gold: u8, silver: u8, copper: u8) -> Self {
+    /// Normalized like [`Coins::from_copper`], so `new(0, 150, 0)` is 1 gold
+    /// 50 silver.
+    pub const fn new(gold: u8, silver: u8, copper: u8) -> Self {
+        Self::from_copper(gold as usize * 10_000 + silver as usize * 100 + copper as usize)
+    }
+    /// Adds `platinum` coins on top, saturating at [`Coins::MAX`].
+    pub const fn with_platinum(self, platinum: u8) -> Self {
+        Self::from_copper(self.as_copper() + platinum as usize * 1_000_000)
+    }
+    /// The most that fits: 255 platinum 99 gold 99 silver 99 copper.
+    pub const MAX: Self = Self {
+        platinum: u8::MAX,
+        gold: 99,
+        silver: 99,
+        copper: 99,
+    };
+
+    /// Breaks an amount of copper down into platinum, gold, silver and
+    /// copper. Amounts beyond [`Coins::MAX`] saturate to it.
+    pub const fn from_copper(copper: usize) -> Self {
+        let max = Self::MAX.as_copper();
+        let copper = if copper < max { copper } else { max };
         Self {
-            gold,
-            silver,
-            copper,
+            platinum: (copper / 1_000_000) as u8,
+            gold: (copper / 10_000 % 100) as u8,
+            silver: (copper / 100 % 100) as u8,
+            copper: (copper % 100) as u8,
         }
     }
-    pub fn as_copper(&self) -> usize {
-        (self.copper as usize
-            + (self.silver as usize * 100usize)
-            + (self.gold as usize * 10000usize)) as usize
+    pub const fn as_copper(&self) -> usize {
+        self.copper as usize
+            + self.silver as usize * 100
+            + self.gold as usize * 10_000
+            + self.platinum as usize * 1_000_000
+    }
+    /// The same amount with every denomination below 100, e.g. 150 silver
+    /// becomes 1 gold 50 silver.
+    pub fn normalized(&self) -> Self {
+        Self::from_copper(self.as_copper())
+    }
+}
+
+impl PartialEq for Coins {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_copper() == other.as_copper()
+    }
+}
+
+impl Eq for Coins {}
+
+impl PartialOrd for Coins {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Coins {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_copper().cmp(&other.as_copper())
+    }
+}
+
+impl Add for Coins {
+    type Output = Coins;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_copper(self.as_copper().saturating_add(rhs.as_copper()))
+    }
+}
+
+impl Sum for Coins {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self::from_copper(
+            iter.map(|coins| coins.as_copper())
+                .fold(0, usize::saturating_add),
+        )
+    }
+}
+
+impl<'a> Sum<&'a Coins> for Coins {
+    fn sum<I: Iterator<Item = &'a Coins>>(iter: I) -> Self {
+        Self::from_copper(iter.map(Coins::as_copper).fold(0, usize::saturating_add))
     }
 }
 
@@ -53,63 +280,229 @@ impl Display for Coins {
             return write!(f, "Nothing");
         }
 
-        let gold = if self.gold > 0 {
-            format!("{} gold", self.gold)
-        } else {
-            String::new()
-        };
-        let silver = if self.silver > 0 {
-            format!(" {} silver", self.silver)
-        } else {
-            String::new()
-        };
-        let copper = if self.copper > 0 {
-            format!(" {} copper", self.copper)
-        } else {
-            String::new()
-        };
+        let coins = self.normalized();
+        let parts = [
+            (coins.platinum, "platinum"),
+            (coins.gold, "gold"),
+            (coins.silver, "silver"),
+            (coins.copper, "copper"),
+        ];
+        let text: Vec<_> = parts
+            .iter()
+            .filter(|(amount, _)| *amount > 0)
+            .map(|(amount, name)| format!("{amount} {name}"))
+            .collect();
 
-        write!(f, "{gold}{silver}{copper}")
+        write!(f, "{}", text.join(" "))
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Deserialize, Serialize)]
-pub enum Rarity {
-    Common,
-    Uncommon,
-    Rare,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCoinsError {
+    Empty,
+    /// Not a whole number of coins.
+    Amount(String),
+    /// Not one of the coin names or their initials.
+    Unit(String),
 }
 
-impl Display for Rarity {
+impl Display for ParseCoinsError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{self:?}")
+        match self {
+            ParseCoinsError::Empty => write!(f, "no coins given"),
+            ParseCoinsError::Amount(amount) => write!(f, "{amount:?} is not an amount of coins"),
+            ParseCoinsError::Unit(unit) => write!(f, "{unit:?} is not a kind of coin"),
+        }
     }
 }
 
-impl Ord for Rarity {
-    fn cmp(&self, other: &Self) -> Ordering {
-        use Ordering::*;
-        use Rarity::*;
+impl std::error::Error for ParseCoinsError {}
 
-        match self {
-            Common => match other {
-                Common => Equal,
-                _ => Greater,
-            },
-            Uncommon => match other {
-                Common => Less,
-                Uncommon => Equal,
-                _ => Greater,
-            },
-            Rare => match other {
-                Common | Uncommon => Less,
-                Rare => Equal,
+impl FromStr for Coins {
+    type Err = ParseCoinsError;
+
+    /// Parses what [`Display`] produces, e.g. `1 gold 50 silver`, as well as
+    /// the short form `1g 50s`. Denominations may be given in any order and
+    /// above 100, and are normalized.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("nothing") {
+            return Ok(Coins::new(0, 0, 0));
+        }
+
+        if s.is_empty() {
+            return Err(ParseCoinsError::Empty);
+        }
+
+        let mut copper = 0usize;
+        let mut tokens = s.split_whitespace();
+        while let Some(token) = tokens.next() {
+            let digits = token
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(token.len());
+            let (amount, unit) = token.split_at(digits);
+            let unit = if unit.is_empty() {
+                tokens.next().ok_or(ParseCoinsError::Unit(String::new()))?
+            } else {
+                unit
+            };
+            let amount: usize = amount
+                .parse()
+                .map_err(|_| ParseCoinsError::Amount(token.to_string()))?;
+            let value = match unit.to_ascii_lowercase().as_str() {
+                "p" | "platinum" => 1_000_000,
+                "g" | "gold" => 10_000,
+                "s" | "silver" => 100,
+                "c" | "copper" => 1,
+                _ => return Err(ParseCoinsError::Unit(unit.to_string())),
+            };
+            copper = amount
+                .checked_mul(value)
+                .and_then(|amount| copper.checked_add(amount))
+                .ok_or_else(|| ParseCoinsError::Amount(token.to_string()))?;
+        }
+
+        Ok(Self::from_copper(copper))
+    }
+}
+
+impl Serialize for Coins {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.as_copper() as u64)
+    }
+}
+
+impl<'de> Deserialize<'de> for Coins {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// Datasets written before coins were stored as copper spell out
+        /// each denomination; text such as `"1g 50s"` is accepted too.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Copper(u64),
+            Text(String),
+            Parts {
+                #[serde(default)]
+                platinum: u8,
+                gold: u8,
+                silver: u8,
+                copper: u8,
             },
         }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Copper(copper) => Ok(Self::from_copper(copper as usize)),
+            Repr::Text(text) => text.parse().map_err(serde::de::Error::custom),
+            Repr::Parts {
+                platinum,
+                gold,
+                silver,
+                copper,
+            } => Ok(Self::new(gold, silver, copper).with_platinum(platinum)),
+        }
     }
 }
 
+/// The least and most money a single kill can drop.
+///
+/// Drops in the game vary with luck, so an enemy worth [`Coins`] on paper
+/// drops somewhere around that amount, see [`CoinRange::around`].
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CoinRange {
+    pub min: Coins,
+    pub max: Coins,
+}
+
+impl CoinRange {
+    pub const fn new(min: Coins, max: Coins) -> Self {
+        Self { min, max }
+    }
+    /// The spread of a drop worth `base`, as the game rolls it: up to 20%
+    /// either way, then lucky rolls of 1 in 5, 10, 15 and 20 that raise it
+    /// by up to another 10%, 20%, 30% and 40% each. The range is lopsided,
+    /// from 80% to a little under 290% of `base`.
+    pub fn around(base: &Coins) -> Self {
+        const PERCENT: [u128; 5] = [120, 110, 120, 130, 140];
+        let copper = base.as_copper() as u128;
+        let max = PERCENT.iter().fold(copper, |max, percent| max * percent)
+            / 100u128.pow(PERCENT.len() as u32);
+        Self {
+            min: Coins::from_copper((copper * 4 / 5) as usize),
+            max: Coins::from_copper(usize::try_from(max).unwrap_or(usize::MAX)),
+        }
+    }
+    pub fn midpoint(&self) -> Coins {
+        Coins::from_copper((self.min.as_copper() + self.max.as_copper()) / 2)
+    }
+    pub fn contains(&self, coins: &Coins) -> bool {
+        (&self.min..=&self.max).contains(&coins)
+    }
+    /// Scales both ends by `numerator / denominator`.
+    fn scale(&self, numerator: usize, denominator: usize) -> Self {
+        let scale = |coins: &Coins| Coins::from_copper(coins.as_copper() * numerator / denominator);
+        Self {
+            min: scale(&self.min),
+            max: scale(&self.max),
+        }
+    }
+}
+
+impl Display for CoinRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.min == self.max {
+            write!(f, "{}", self.min)
+        } else {
+            write!(f, "{} to {}", self.min, self.max)
+        }
+    }
+}
+
+/// How rarely an enemy is encountered, following the star rating of the
+/// in-game bestiary.
+///
+/// Rarer tiers compare greater, so `Common < Uncommon < ... < Legendary`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    VeryRare,
+    Legendary,
+}
+
+impl Rarity {
+    /// Number of bestiary stars, from 1 to 5.
+    pub fn stars(self) -> u8 {
+        self as u8 + 1
+    }
+    /// The inverse of [`Rarity::stars`].
+    pub fn from_stars(stars: u8) -> Option<Self> {
+        match stars {
+            1 => Some(Rarity::Common),
+            2 => Some(Rarity::Uncommon),
+            3 => Some(Rarity::Rare),
+            4 => Some(Rarity::VeryRare),
+            5 => Some(Rarity::Legendary),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Rarity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Rarity::Common => "Common",
+            Rarity::Uncommon => "Uncommon",
+            Rarity::Rare => "Rare",
+            Rarity::VeryRare => "Very Rare",
+            Rarity::Legendary => "Legendary",
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub enum OrderingText {
     Less,
     Equal,
@@ -132,91 +525,500 @@ impl From<Ordering> for OrderingText {
     }
 }
 
+/// How far a numeric guess is from the answer, relative to the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum Proximity {
+    Exact,
+    /// Within [`Thresholds::close`] percent.
+    Close,
+    /// Within [`Thresholds::near`] percent.
+    Near,
+    Far,
+}
+
+impl Proximity {
+    pub fn new(guess: u64, answer: u64, thresholds: &Thresholds) -> Self {
+        let delta = guess.abs_diff(answer) as u128 * 100;
+        let answer = answer as u128;
+        if delta == 0 {
+            Proximity::Exact
+        } else if delta <= thresholds.close as u128 * answer {
+            Proximity::Close
+        } else if delta <= thresholds.near as u128 * answer {
+            Proximity::Near
+        } else {
+            Proximity::Far
+        }
+    }
+}
+
+/// The two values behind a [`Comparison`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Values {
+    pub guess: u64,
+    pub answer: u64,
+}
+
+impl Values {
+    /// The guess minus the answer.
+    pub fn delta(&self) -> i64 {
+        self.guess as i64 - self.answer as i64
+    }
+    /// How many times larger the guess is than the answer. Two zeros are
+    /// equal, so their ratio is 1; anything else over zero is infinite.
+    pub fn ratio(&self) -> f64 {
+        match (self.guess, self.answer) {
+            (0, 0) => 1.0,
+            (guess, answer) => guess as f64 / answer as f64,
+        }
+    }
+}
+
+/// The result of comparing a numeric attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Comparison {
+    pub ordering: OrderingText,
+    /// Only set if [`Rules::proximity`] is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proximity: Option<Proximity>,
+    /// The values that were compared, only set if [`Rules::deltas`] is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<Values>,
+}
+
+impl Comparison {
+    pub fn new(guess: u64, answer: u64, rules: &Rules) -> Self {
+        Self {
+            ordering: guess.cmp(&answer).into(),
+            proximity: rules
+                .proximity
+                .as_ref()
+                .map(|thresholds| Proximity::new(guess, answer, thresholds)),
+            values: rules.deltas.then_some(Values { guess, answer }),
+        }
+    }
+    pub fn is_eq(&self) -> bool {
+        self.ordering.is_eq()
+    }
+    /// See [`Values::delta`].
+    pub fn delta(&self) -> Option<i64> {
+        self.values.as_ref().map(Values::delta)
+    }
+    /// See [`Values::ratio`].
+    pub fn ratio(&self) -> Option<f64> {
+        self.values.as_ref().map(Values::ratio)
+    }
+}
+
+/// The stats of an enemy that change with world difficulty.
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Stats {
+    pub life: u32,
+    pub defence: u16,
+    /// Contact damage.
+    #[serde(default)]
+    pub damage: u16,
+    /// Percentage of knockback ignored, from 0 to 100 (immune).
+    #[serde(default)]
+    pub knockback_resist: u8,
+    pub coins: Coins,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(try_from = "EnemyRepr")]
 pub struct Enemy {
     pub id: u16,
-    pub name: String,
-    pub life: u16,
+    pub name: Cow<'static, str>,
+    pub life: u32,
     pub defence: u16,
+    /// Contact damage, or zero for enemies that only hurt with projectiles.
+    #[serde(default)]
+    pub damage: u16,
+    /// Percentage of knockback ignored, from 0 to 100 (immune).
+    #[serde(default)]
+    pub knockback_resist: u8,
     pub coins: Coins,
-    pub biomes: Box<[Biome]>,
-    pub events: Box<[Event]>,
-    pub layers: Vec<Layer>,
+    /// What a kill drops, if not the usual spread around `coins`, see
+    /// [`CoinRange::around`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coin_range: Option<CoinRange>,
+    pub biomes: Cow<'static, [Biome]>,
+    #[serde(default)]
+    pub time: Time,
+    pub events: Cow<'static, [Event]>,
+    pub layers: Cow<'static, [Layer]>,
     pub rarity: Rarity,
+    pub stage: Stage,
+    /// Not compared by [`Enemy::diff`], but useful to pick a game mode.
+    pub category: Category,
+    pub kinds: Cow<'static, [Kind]>,
+    /// Expert mode stats, if they differ from [`Difficulty::scale`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expert: Option<Stats>,
+    /// Master mode stats, if they differ from [`Difficulty::scale`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub master: Option<Stats>,
 }
 impl Enemy {
-    pub fn new(
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
         id: u16,
-        name: impl AsRef<str>,
-        life: u16,
+        name: &'static str,
+        life: u32,
         defence: u16,
+        damage: u16,
+        knockback_resist: u8,
         coins: Coins,
-        biomes: impl IntoIterator<Item = Biome>,
-        events: impl IntoIterator<Item = Event>,
-        layers: impl IntoIterator<Item = Layer>,
+        biomes: &'static [Biome],
+        time: Time,
+        events: &'static [Event],
+        layers: &'static [Layer],
         rarity: Rarity,
+        stage: Stage,
+        category: Category,
+        kinds: &'static [Kind],
     ) -> Self {
-        let name = name.as_ref().to_string();
-        let biomes = biomes.into_iter().collect();
-        let events = events.into_iter().collect();
-        let layers = layers.into_iter().collect();
-
         Self {
             id,
-            name,
+            name: Cow::Borrowed(name),
             life,
             defence,
+            damage,
+            knockback_resist,
             coins,
-            biomes,
-            events,
-            layers,
+            coin_range: None,
+            biomes: Cow::Borrowed(biomes),
+            time,
+            events: Cow::Borrowed(events),
+            layers: Cow::Borrowed(layers),
             rarity,
+            stage,
+            category,
+            kinds: Cow::Borrowed(kinds),
+            expert: None,
+            master: None,
+        }
+    }
+    pub const fn with_expert(mut self, stats: Stats) -> Self {
+        self.expert = Some(stats);
+        self
+    }
+    pub const fn with_master(mut self, stats: Stats) -> Self {
+        self.master = Some(stats);
+        self
+    }
+    pub const fn with_coin_range(mut self, range: CoinRange) -> Self {
+        self.coin_range = Some(range);
+        self
+    }
+    /// The money a kill drops in a world of the given difficulty. A stored
+    /// range is scaled like [`Stats::coins`] is.
+    pub fn drop_range(&self, difficulty: Difficulty) -> CoinRange {
+        let coins = self.stats(difficulty).coins;
+        match &self.coin_range {
+            Some(range) if self.coins.as_copper() > 0 => {
+                range.scale(coins.as_copper(), self.coins.as_copper())
+            }
+            Some(range) => range.clone(),
+            None => CoinRange::around(&coins),
         }
     }
+    fn coin_value(&self, rules: &Rules) -> usize {
+        match rules.coin_basis {
+            CoinBasis::Base => self.stats(rules.difficulty).coins.as_copper(),
+            CoinBasis::Midpoint => self.drop_range(rules.difficulty).midpoint().as_copper(),
+        }
+    }
+    /// Combat stats and coins in a world of the given difficulty.
+    pub fn stats(&self, difficulty: Difficulty) -> Stats {
+        let classic = Stats {
+            life: self.life,
+            defence: self.defence,
+            damage: self.damage,
+            knockback_resist: self.knockback_resist,
+            coins: self.coins.clone(),
+        };
+        let stored = match difficulty {
+            Difficulty::Classic => return classic,
+            Difficulty::Expert => &self.expert,
+            Difficulty::Master => &self.master,
+        };
+
+        stored
+            .clone()
+            .unwrap_or_else(|| difficulty.scale(&classic, self.category))
+    }
+    /// Compares this enemy, as a guess, against `other` as the answer.
     pub fn diff(&self, other: &Enemy) -> EnemyDiff {
-        let name = self.name == other.name;
-        let life = self.life.cmp(&other.life).into();
-        let defence = self.defence.cmp(&other.defence).into();
-        let coins = self.coins.as_copper().cmp(&other.coins.as_copper()).into();
-        let biomes = Diff::with(&*self.biomes, &*other.biomes);
-        let events = Diff::with(&*self.events, &*other.events);
-        let layers = Diff::with(&*self.layers, &*other.layers);
-        let rarity = self.rarity.cmp(&other.rarity).into();
+        self.diff_with(other, &Rules::default())
+    }
+    /// Like [`Enemy::diff`], under the given rules. Columns the rules leave
+    /// out are `None`.
+    pub fn diff_with(&self, other: &Enemy, rules: &Rules) -> EnemyDiff {
+        let (guess, answer) = (self.stats(rules.difficulty), other.stats(rules.difficulty));
+        let compare = |column| rules.compares(column);
+        let numeric = |column, guess: u64, answer: u64| {
+            compare(column).then(|| Comparison::new(guess, answer, rules))
+        };
 
         EnemyDiff {
-            name,
-            life,
-            defence,
-            coins,
-            biomes,
-            events,
-            layers,
-            rarity,
+            id: self.id == other.id,
+            name: self.name == other.name,
+            life: numeric(Column::Life, guess.life as u64, answer.life as u64),
+            defence: numeric(Column::Defence, guess.defence as u64, answer.defence as u64),
+            coins: numeric(
+                Column::Coins,
+                self.coin_value(rules) as u64,
+                other.coin_value(rules) as u64,
+            ),
+            biomes: compare(Column::Biomes).then(|| Diff::with(&self.biomes, &other.biomes)),
+            time: compare(Column::Time).then(|| self.time == other.time),
+            events: compare(Column::Events).then(|| Diff::with(&self.events, &other.events)),
+            layers: compare(Column::Layers).then(|| Diff::with(&self.layers, &other.layers)),
+            rarity: compare(Column::Rarity).then(|| self.rarity.cmp(&other.rarity).into()),
+            stage: compare(Column::Stage).then(|| self.stage.cmp(&other.stage).into()),
+            kinds: compare(Column::Kinds).then(|| Diff::with(&self.kinds, &other.kinds)),
+            damage: numeric(Column::Damage, guess.damage as u64, answer.damage as u64),
+            knockback: numeric(
+                Column::Knockback,
+                guess.knockback_resist as u64,
+                answer.knockback_resist as u64,
+            ),
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+/// How [`Enemy`] is read, accepting datasets from before [`Time`] was split
+/// out of [`Biome`].
+///
+/// Those datasets also predate stages, categories and kinds, so enemies in
+/// them read as regular pre-Hardmode enemies of no particular kind.
+#[derive(Deserialize)]
+struct EnemyRepr {
+    id: u16,
+    name: Cow<'static, str>,
+    life: u32,
+    defence: u16,
+    #[serde(default)]
+    damage: u16,
+    #[serde(default)]
+    knockback_resist: u8,
+    coins: Coins,
+    #[serde(default)]
+    coin_range: Option<CoinRange>,
+    biomes: Vec<LegacyBiome>,
+    #[serde(default, deserialize_with = "present")]
+    time: Option<Time>,
+    events: Cow<'static, [Event]>,
+    layers: Cow<'static, [Layer]>,
+    rarity: Rarity,
+    #[serde(default)]
+    stage: Stage,
+    #[serde(default)]
+    category: Category,
+    #[serde(default)]
+    kinds: Cow<'static, [Kind]>,
+    #[serde(default)]
+    expert: Option<Stats>,
+    #[serde(default)]
+    master: Option<Stats>,
+}
+
+/// Reads an optional field written as its bare value, which RON would
+/// otherwise expect as `Some(..)`. A missing field is left to `default`.
+fn present<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<Option<T>, D::Error> {
+    T::deserialize(deserializer).map(Some)
+}
+
+/// A [`Biome`], or a time of day listed among the biomes.
+enum LegacyBiome {
+    Place(Biome),
+    Time(Time),
+}
+
+impl<'de> Deserialize<'de> for LegacyBiome {
+    /// Goes by the variant name rather than `#[serde(untagged)]`, which
+    /// can't tell unit variants apart in RON.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        /// A variant name, read as an identifier.
+        struct Name(String);
+
+        impl<'de> Deserialize<'de> for Name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl serde::de::Visitor<'_> for Visitor {
+                    type Value = Name;
+
+                    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(f, "a variant name")
+                    }
+                    fn visit_str<E: serde::de::Error>(self, name: &str) -> Result<Name, E> {
+                        Ok(Name(name.to_owned()))
+                    }
+                }
+
+                deserializer.deserialize_identifier(Visitor)
+            }
+        }
+
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = LegacyBiome;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "a biome or a time of day")
+            }
+            fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+                let (Name(name), variant) = data.variant()?;
+                variant.unit_variant()?;
+
+                let place: Result<_, A::Error> =
+                    Biome::deserialize(name.clone().into_deserializer());
+                let time: Result<_, A::Error> = Time::deserialize(name.clone().into_deserializer());
+                match (place, time) {
+                    (Ok(place), _) => Ok(LegacyBiome::Place(place)),
+                    (_, Ok(time)) => Ok(LegacyBiome::Time(time)),
+                    _ => {
+                        let expected: Vec<_> = Biome::ALL
+                            .iter()
+                            .map(|biome| format!("`{biome:?}`"))
+                            .chain([Time::Day, Time::Night].map(|time| format!("`{time:?}`")))
+                            .collect();
+                        Err(serde::de::Error::custom(format_args!(
+                            "unknown variant `{name}`, expected one of {}",
+                            expected.join(", ")
+                        )))
+                    }
+                }
+            }
+        }
+
+        deserializer.deserialize_enum("Biome", &[], Visitor)
+    }
+}
+
+impl TryFrom<EnemyRepr> for Enemy {
+    type Error = String;
+
+    fn try_from(repr: EnemyRepr) -> Result<Self, Self::Error> {
+        let mut biomes = vec![];
+        let mut times = vec![];
+        for biome in repr.biomes {
+            match biome {
+                LegacyBiome::Place(biome) => biomes.push(biome),
+                LegacyBiome::Time(time) => times.push(time),
+            }
+        }
+        // Listing both day and night meant any time at all.
+        let listed = match (times.contains(&Time::Day), times.contains(&Time::Night)) {
+            (true, false) => Some(Time::Day),
+            (false, true) => Some(Time::Night),
+            (true, true) => Some(Time::Any),
+            (false, false) => None,
+        };
+        let time = match (repr.time, listed) {
+            (Some(time), Some(listed)) if time != listed => {
+                return Err(format!(
+                    "{}: time is {time} but its biomes say {listed}",
+                    repr.name
+                ))
+            }
+            (time, listed) => time.or(listed).unwrap_or_default(),
+        };
+        // Only a time of day meant anywhere at that time.
+        if biomes.is_empty() && !times.is_empty() {
+            biomes.push(Biome::Any);
+        }
+
+        Ok(Self {
+            id: repr.id,
+            name: repr.name,
+            life: repr.life,
+            defence: repr.defence,
+            damage: repr.damage,
+            knockback_resist: repr.knockback_resist,
+            coins: repr.coins,
+            coin_range: repr.coin_range,
+            biomes: biomes.into(),
+            time,
+            events: repr.events,
+            layers: repr.layers,
+            rarity: repr.rarity,
+            stage: repr.stage,
+            category: repr.category,
+            kinds: repr.kinds,
+            expert: repr.expert,
+            master: repr.master,
+        })
+    }
+}
+
+/// How a guess compared against the answer. Every attribute but the name is
+/// `None` unless its [`Column`] was enabled in the [`Rules`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
 pub struct EnemyDiff {
+    /// Whether the guess is the answer.
+    pub id: bool,
     pub name: bool,
-    pub life: OrderingText,
-    pub defence: OrderingText,
-    pub coins: OrderingText,
-    pub biomes: Diff<Biome>,
-    pub events: Diff<Event>,
-    pub layers: Diff<Layer>,
-    pub rarity: OrderingText,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub life: Option<Comparison>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub defence: Option<Comparison>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coins: Option<Comparison>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub biomes: Option<Diff<Biome>>,
+    /// Whether both spawn at the same time of day.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<Diff<Event>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layers: Option<Diff<Layer>>,
+    /// `Greater` if the guess is rarer than the answer, i.e. the answer is
+    /// more common, and `Less` if the answer is rarer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rarity: Option<OrderingText>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<OrderingText>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kinds: Option<Diff<Kind>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub damage: Option<Comparison>,
+    /// Knockback resistance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub knockback: Option<Comparison>,
 }
 
 impl EnemyDiff {
-    /// Whether or not this diff was the result of two of the same enemy.
+    /// Whether the guess was the answer, i.e. the game is won.
     pub fn is_same(&self) -> bool {
-        self.name
-            && self.life.is_eq()
-            && self.defence.is_eq()
-            && self.coins.is_eq()
-            && self.biomes.wrong.is_empty()
-            && self.events.wrong.is_empty()
-            && self.layers.wrong.is_empty()
+        self.id && self.is_indistinguishable()
+    }
+    /// Whether every compared column matched, so that going by the columns
+    /// alone the guess can't be told apart from the answer. Names and ids
+    /// aren't columns, so two different enemies can be indistinguishable.
+    pub fn is_indistinguishable(&self) -> bool {
+        let ordering =
+            |ordering: &Option<OrderingText>| ordering.as_ref().is_none_or(OrderingText::is_eq);
+        let numeric =
+            |comparison: &Option<Comparison>| comparison.as_ref().is_none_or(Comparison::is_eq);
+
+        numeric(&self.life)
+            && numeric(&self.defence)
+            && numeric(&self.coins)
+            && self.biomes.as_ref().is_none_or(Diff::is_exact)
+            && self.time.unwrap_or(true)
+            && self.events.as_ref().is_none_or(Diff::is_exact)
+            && self.layers.as_ref().is_none_or(Diff::is_exact)
+            && ordering(&self.rarity)
+            && ordering(&self.stage)
+            && self.kinds.as_ref().is_none_or(Diff::is_exact)
+            && numeric(&self.damage)
+            && numeric(&self.knockback)
     }
 }
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    prelude::Enemy,
+    rules::Rules,
+    solver::{best_guess, Strategy},
+};
+
+/// How hard an enemy is to find as the answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum Rating {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Rating {
+    /// Rates a puzzle by how many guesses the solver needed.
+    pub fn from_guesses(guesses: usize) -> Self {
+        match guesses {
+            0..=2 => Rating::Easy,
+            3 => Rating::Medium,
+            _ => Rating::Hard,
+        }
+    }
+}
+
+/// The outcome of letting the solver play against an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assessment<'a> {
+    pub enemy: &'a Enemy,
+    /// Guesses needed, counting the winning one.
+    pub guesses: usize,
+    pub rating: Rating,
+}
+
+/// Plays the game against `answer`, always making the solver's best guess.
+pub fn assess<'a>(
+    answer: &'a Enemy,
+    enemies: &[Enemy],
+    rules: &Rules,
+    strategy: Strategy,
+) -> Assessment<'a> {
+    let all: Vec<_> = enemies.iter().collect();
+    let opening = best_guess(enemies, &all, rules, strategy);
+
+    assess_from(answer, enemies, all, opening, rules, strategy)
+}
+
+/// Assesses every enemy in `enemies` as the answer.
+pub fn report<'a>(enemies: &'a [Enemy], rules: &Rules, strategy: Strategy) -> Vec<Assessment<'a>> {
+    let all: Vec<_> = enemies.iter().collect();
+    // The first guess never depends on the answer, so it's only worked out once.
+    let opening = best_guess(enemies, &all, rules, strategy);
+
+    enemies
+        .iter()
+        .map(|answer| assess_from(answer, enemies, all.clone(), opening, rules, strategy))
+        .collect()
+}
+
+fn assess_from<'a>(
+    answer: &'a Enemy,
+    enemies: &[Enemy],
+    mut candidates: Vec<&Enemy>,
+    opening: Option<&Enemy>,
+    rules: &Rules,
+    strategy: Strategy,
+) -> Assessment<'a> {
+    let mut guesses = 0;
+    let mut next = opening;
+
+    while let Some(guess) = next {
+        guesses += 1;
+        let feedback = guess.diff_with(answer, rules);
+        if feedback.is_same() {
+            break;
+        }
+
+        candidates.retain(|candidate| guess.diff_with(candidate, rules) == feedback);
+        next = best_guess(enemies, &candidates, rules, strategy);
+    }
+
+    Assessment {
+        enemy: answer,
+        guesses,
+        rating: Rating::from_guesses(guesses),
+    }
+}
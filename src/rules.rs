@@ -0,0 +1,235 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    categories::Category,
+    prelude::{Coins, Flag, Set, Stats},
+};
+
+/// The world difficulty enemy stats are taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub enum Difficulty {
+    #[default]
+    Classic,
+    Expert,
+    Master,
+}
+
+impl Difficulty {
+    /// Scales classic stats the way the game does for most enemies: regular
+    /// enemies get twice (Expert) or three times (Master) the life, bosses
+    /// 1.4 or 1.785 times, and everything drops 2.5 times the money.
+    /// Contact damage is doubled or tripled, and enemies take a tenth
+    /// (Expert) or a fifth (Master) less knockback. Defence is never scaled.
+    ///
+    /// Bosses that don't follow this carry their own stats, see
+    /// [`crate::prelude::Enemy::with_expert`].
+    pub fn scale(self, classic: &Stats, category: Category) -> Stats {
+        // Life as a fraction, the damage multiplier and tenths of knockback
+        // still taken.
+        let (numerator, denominator, damage, knockback) = match (self, category) {
+            (Difficulty::Classic, _) => return classic.clone(),
+            (Difficulty::Expert, Category::Boss) => (1400, 1000, 2, 9),
+            (Difficulty::Expert, _) => (2, 1, 2, 9),
+            (Difficulty::Master, Category::Boss) => (1785, 1000, 3, 8),
+            (Difficulty::Master, _) => (3, 1, 3, 8),
+        };
+        let life = (classic.life as u64 * numerator / denominator) as u32;
+        let damage = classic.damage.saturating_mul(damage);
+        let knockback_taken = 100 - classic.knockback_resist.min(100) as u16;
+        let knockback_resist = (100 - knockback_taken * knockback / 10) as u8;
+        let coins = Coins::from_copper(classic.coins.as_copper() * 5 / 2);
+
+        Stats {
+            life,
+            defence: classic.defence,
+            damage,
+            knockback_resist,
+            coins,
+        }
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+/// An attribute that can be compared by [`crate::prelude::Enemy::diff_with`],
+/// in the order columns are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Column {
+    Life,
+    Defence,
+    Coins,
+    Biomes,
+    Time,
+    Events,
+    Layers,
+    Rarity,
+    Stage,
+    Kinds,
+    Damage,
+    Knockback,
+}
+
+impl Column {
+    /// The columns of the classic game.
+    pub const DEFAULT: &'static [Self] = &[
+        Column::Life,
+        Column::Defence,
+        Column::Coins,
+        Column::Biomes,
+        Column::Time,
+        Column::Events,
+        Column::Layers,
+        Column::Rarity,
+        Column::Stage,
+        Column::Kinds,
+    ];
+}
+
+impl Display for Column {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Column::Life => "Life",
+            Column::Defence => "Defence",
+            Column::Coins => "Coins",
+            Column::Biomes => "Biomes",
+            Column::Time => "Time",
+            Column::Events => "Events",
+            Column::Layers => "Layers",
+            Column::Rarity => "Rarity",
+            Column::Stage => "Stage",
+            Column::Kinds => "Kinds",
+            Column::Damage => "Damage",
+            Column::Knockback => "Knockback resistance",
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+impl Flag for Column {
+    const ALL: &'static [Self] = &[
+        Column::Life,
+        Column::Defence,
+        Column::Coins,
+        Column::Biomes,
+        Column::Time,
+        Column::Events,
+        Column::Layers,
+        Column::Rarity,
+        Column::Stage,
+        Column::Kinds,
+        Column::Damage,
+        Column::Knockback,
+    ];
+
+    fn index(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Settings that change how guesses are compared.
+///
+/// Names are always compared; every other attribute is a [`Column`] that can
+/// be turned on or off, e.g. hiding coins for an easier game or adding
+/// damage for a harder one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Rules {
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    #[serde(default = "default_columns")]
+    pub columns: Set<Column>,
+    /// Whether numeric columns say how close a guess is, see
+    /// [`crate::prelude::Comparison::proximity`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proximity: Option<Thresholds>,
+    /// Whether numeric columns give the values compared, and so the exact
+    /// difference, see [`crate::prelude::Comparison::values`].
+    #[serde(default)]
+    pub deltas: bool,
+    #[serde(default)]
+    pub coin_basis: CoinBasis,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            difficulty: Difficulty::default(),
+            columns: default_columns(),
+            proximity: None,
+            deltas: false,
+            coin_basis: CoinBasis::default(),
+        }
+    }
+}
+
+impl Rules {
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = difficulty;
+        self
+    }
+    /// Compares exactly the given columns.
+    pub fn with_columns(mut self, columns: impl IntoIterator<Item = Column>) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+    pub fn with_column(mut self, column: Column) -> Self {
+        self.columns.insert(column);
+        self
+    }
+    pub fn without_column(mut self, column: Column) -> Self {
+        self.columns.remove(column);
+        self
+    }
+    pub fn with_proximity(mut self, thresholds: Thresholds) -> Self {
+        self.proximity = Some(thresholds);
+        self
+    }
+    pub fn with_deltas(mut self, deltas: bool) -> Self {
+        self.deltas = deltas;
+        self
+    }
+    pub fn with_coin_basis(mut self, coin_basis: CoinBasis) -> Self {
+        self.coin_basis = coin_basis;
+        self
+    }
+    pub fn compares(&self, column: Column) -> bool {
+        self.columns.contains(column)
+    }
+}
+
+/// Which value the coins column compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub enum CoinBasis {
+    /// The listed [`crate::prelude::Enemy::coins`].
+    #[default]
+    Base,
+    /// The middle of [`crate::prelude::Enemy::drop_range`].
+    Midpoint,
+}
+
+/// Percentages of the answer's value a guess can be off by and still count
+/// as close or near, see [`crate::prelude::Proximity`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Thresholds {
+    pub close: u16,
+    pub near: u16,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            close: 10,
+            near: 25,
+        }
+    }
+}
+
+fn default_columns() -> Set<Column> {
+    Column::DEFAULT.iter().copied().collect()
+}
@@ -0,0 +1,132 @@
+use crate::prelude::Enemy;
+
+/// Folds a name into the form used for matching: lowercase, without
+/// diacritics or punctuation, and with single spaces between words.
+pub fn normalize(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à'..='å' | 'ā' | 'ă' | 'ą' => folded.push('a'),
+            'ç' | 'ć' | 'č' => folded.push('c'),
+            'è'..='ë' | 'ē' | 'ė' | 'ę' | 'ě' => folded.push('e'),
+            'ì'..='ï' | 'ī' | 'į' => folded.push('i'),
+            'ñ' | 'ń' | 'ň' => folded.push('n'),
+            'ò'..='ö' | 'ø' | 'ō' | 'ő' => folded.push('o'),
+            'ù'..='ü' | 'ū' | 'ů' | 'ű' => folded.push('u'),
+            'ý' | 'ÿ' => folded.push('y'),
+            'ś' | 'š' => folded.push('s'),
+            'ź' | 'ż' | 'ž' => folded.push('z'),
+            'ß' => folded.push_str("ss"),
+            'æ' => folded.push_str("ae"),
+            'œ' => folded.push_str("oe"),
+            // "Hoppin' Jack" and "Dr. Man Fly" are usually typed without punctuation.
+            '\'' | '’' | '.' => {}
+            c if c.is_alphanumeric() => folded.push(c),
+            _ => folded.push(' '),
+        }
+    }
+
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Number of single-character insertions, deletions, substitutions and
+/// adjacent transpositions needed to turn `a` into `b`.
+pub fn distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let width = b.len() + 1;
+    let mut rows = vec![0; (a.len() + 1) * width];
+
+    for (j, cell) in rows.iter_mut().enumerate().take(width) {
+        *cell = j;
+    }
+    for i in 1..=a.len() {
+        rows[i * width] = i;
+        for j in 1..=b.len() {
+            let cost = (a[i - 1] != b[j - 1]) as usize;
+            let mut best = (rows[(i - 1) * width + j] + 1)
+                .min(rows[i * width + j - 1] + 1)
+                .min(rows[(i - 1) * width + j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[(i - 2) * width + j - 2] + 1);
+            }
+            rows[i * width + j] = best;
+        }
+    }
+
+    rows[a.len() * width + b.len()]
+}
+
+/// Name lookups over a set of enemies.
+pub struct NameIndex<'a> {
+    entries: Vec<(String, &'a Enemy)>,
+}
+
+impl<'a> NameIndex<'a> {
+    pub fn new(enemies: &'a [Enemy]) -> Self {
+        let mut entries: Vec<_> = enemies
+            .iter()
+            .map(|enemy| (normalize(&enemy.name), enemy))
+            .collect();
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        Self { entries }
+    }
+    /// The enemy with exactly this name, ignoring case, diacritics and punctuation.
+    pub fn exact(&self, name: &str) -> Option<&'a Enemy> {
+        let name = normalize(name);
+        self.entries
+            .binary_search_by(|(key, _)| key.as_str().cmp(&name))
+            .ok()
+            .map(|index| self.entries[index].1)
+    }
+    /// Up to `limit` enemies whose name, or any word in it, starts with `prefix`.
+    ///
+    /// Names that start with the prefix come before names that merely contain
+    /// a word starting with it, and shorter names come first within each group.
+    pub fn complete(&self, prefix: &str, limit: usize) -> Vec<&'a Enemy> {
+        let prefix = normalize(prefix);
+        if prefix.is_empty() {
+            return vec![];
+        }
+
+        let mut matches: Vec<_> = self
+            .entries
+            .iter()
+            .filter_map(|(key, enemy)| {
+                let rank = if key.starts_with(&prefix) {
+                    0
+                } else if key
+                    .match_indices(&prefix)
+                    .any(|(at, _)| key[..at].ends_with(' '))
+                {
+                    1
+                } else {
+                    return None;
+                };
+                Some((rank, key.len(), key, *enemy))
+            })
+            .collect();
+        matches.sort_by(|a, b| (a.0, a.1, a.2).cmp(&(b.0, b.1, b.2)));
+
+        matches
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, _, enemy)| enemy)
+            .collect()
+    }
+    /// Enemies within `max_distance` edits of `name`, closest first.
+    pub fn fuzzy(&self, name: &str, max_distance: usize) -> Vec<&'a Enemy> {
+        let name = normalize(name);
+        let mut matches: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|(key, _)| key.chars().count().abs_diff(name.chars().count()) <= max_distance)
+            .map(|(key, enemy)| (distance(&name, key), key, *enemy))
+            .filter(|(distance, _, _)| *distance <= max_distance)
+            .collect();
+        matches.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        matches.into_iter().map(|(_, _, enemy)| enemy).collect()
+    }
+}
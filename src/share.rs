@@ -0,0 +1,74 @@
+use crate::prelude::{Comparison, Diff, EnemyDiff, Flag, OrderingText, Verdict};
+
+const RIGHT: &str = "🟩";
+const PARTIAL: &str = "🟨";
+const WRONG: &str = "🟥";
+const UP: &str = "⬆️";
+const DOWN: &str = "⬇️";
+
+/// Formats a finished (or abandoned) game as a spoiler-free grid of emoji.
+///
+/// The header holds the puzzle number and the guess count, or `X` if the
+/// answer wasn't found. Each line after it is one guess, with a column for
+/// the name followed by one per compared [`crate::rules::Column`], in declaration order.
+/// Arrows point the way the answer lies from the guess.
+pub fn grid<'a>(
+    puzzle: u32,
+    diffs: impl IntoIterator<Item = &'a EnemyDiff>,
+    max_guesses: usize,
+) -> String {
+    let diffs: Vec<_> = diffs.into_iter().collect();
+    let won = diffs.last().is_some_and(|diff| diff.is_same());
+    let score = if won {
+        diffs.len().to_string()
+    } else {
+        "X".to_string()
+    };
+
+    let mut text = format!("Eowordle #{puzzle} {score}/{max_guesses}");
+    for diff in diffs {
+        text.push('\n');
+        text.push_str(&row(diff));
+    }
+
+    text
+}
+
+/// A single line of the grid.
+pub fn row(diff: &EnemyDiff) -> String {
+    let mut cells = vec![if diff.name { RIGHT } else { WRONG }];
+    cells.extend(diff.life.as_ref().map(numeric));
+    cells.extend(diff.defence.as_ref().map(numeric));
+    cells.extend(diff.coins.as_ref().map(numeric));
+    cells.extend(diff.biomes.as_ref().map(set));
+    cells.extend(diff.time.map(|same| if same { RIGHT } else { WRONG }));
+    cells.extend(diff.events.as_ref().map(set));
+    cells.extend(diff.layers.as_ref().map(set));
+    cells.extend(diff.rarity.as_ref().map(ordering));
+    cells.extend(diff.stage.as_ref().map(ordering));
+    cells.extend(diff.kinds.as_ref().map(set));
+    cells.extend(diff.damage.as_ref().map(numeric));
+    cells.extend(diff.knockback.as_ref().map(numeric));
+
+    cells.concat()
+}
+
+fn numeric(comparison: &Comparison) -> &'static str {
+    ordering(&comparison.ordering)
+}
+
+fn ordering(ordering: &OrderingText) -> &'static str {
+    match ordering {
+        OrderingText::Less => UP,
+        OrderingText::Equal => RIGHT,
+        OrderingText::Greater => DOWN,
+    }
+}
+
+fn set<T: Flag>(diff: &Diff<T>) -> &'static str {
+    match diff.verdict {
+        Verdict::Exact => RIGHT,
+        Verdict::Partial => PARTIAL,
+        Verdict::None => WRONG,
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::{game::Guess, prelude::Enemy, rules::Rules};
+
+/// Whether `candidate` could be the answer given the feedback in `guesses`.
+///
+/// A candidate is consistent when diffing every guess against it reproduces
+/// exactly the feedback that guess received, so this follows the semantics of
+/// [`Enemy::diff_with`] by construction.
+pub fn is_consistent(candidate: &Enemy, guesses: &[Guess], rules: &Rules) -> bool {
+    guesses
+        .iter()
+        .all(|guess| guess.enemy.diff_with(candidate, rules) == guess.diff)
+}
+
+/// Every enemy in `enemies` that could still be the answer.
+pub fn candidates<'a>(enemies: &'a [Enemy], guesses: &[Guess], rules: &Rules) -> Vec<&'a Enemy> {
+    enemies
+        .iter()
+        .filter(|candidate| is_consistent(candidate, guesses, rules))
+        .collect()
+}
+
+/// How to rank guesses against the remaining candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    /// Highest expected information gain.
+    #[default]
+    Entropy,
+    /// Smallest worst-case number of candidates left afterwards.
+    Minimax,
+}
+
+/// How informative a guess would be.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Score<'a> {
+    pub enemy: &'a Enemy,
+    /// Expected information gain, in bits.
+    pub entropy: f64,
+    /// Size of the largest group of candidates that would receive the same feedback.
+    pub worst_case: usize,
+    /// Whether the guess could itself be the answer.
+    pub candidate: bool,
+}
+
+/// Scores `guess` by how it splits `candidates` into groups with identical feedback.
+pub fn score<'a>(guess: &'a Enemy, candidates: &[&Enemy], rules: &Rules) -> Score<'a> {
+    let mut groups = HashMap::new();
+    for candidate in candidates {
+        *groups
+            .entry(guess.diff_with(candidate, rules))
+            .or_insert(0usize) += 1;
+    }
+
+    let total = candidates.len() as f64;
+    let entropy = groups
+        .values()
+        .map(|&size| {
+            let p = size as f64 / total;
+            -p * p.log2()
+        })
+        .sum();
+    let worst_case = groups.values().copied().max().unwrap_or(0);
+    let candidate = candidates.contains(&guess);
+
+    Score {
+        enemy: guess,
+        entropy,
+        worst_case,
+        candidate,
+    }
+}
+
+/// Scores every enemy in `guesses`, best first.
+///
+/// Ties are broken in favour of guesses that could be the answer, then by id.
+pub fn rank<'a>(
+    guesses: &'a [Enemy],
+    candidates: &[&Enemy],
+    rules: &Rules,
+    strategy: Strategy,
+) -> Vec<Score<'a>> {
+    let mut scores: Vec<_> = guesses
+        .iter()
+        .map(|guess| score(guess, candidates, rules))
+        .collect();
+
+    scores.sort_by(|a, b| {
+        let primary = match strategy {
+            Strategy::Entropy => b.entropy.total_cmp(&a.entropy),
+            Strategy::Minimax => a.worst_case.cmp(&b.worst_case),
+        };
+        primary
+            .then_with(|| b.candidate.cmp(&a.candidate))
+            .then_with(|| a.enemy.id.cmp(&b.enemy.id))
+    });
+
+    scores
+}
+
+/// The best guess to make next, or `None` if there is nothing left to guess.
+pub fn best_guess<'a>(
+    guesses: &'a [Enemy],
+    candidates: &[&Enemy],
+    rules: &Rules,
+    strategy: Strategy,
+) -> Option<&'a Enemy> {
+    match candidates {
+        [] => None,
+        // Nothing beats guessing the answer outright.
+        [answer] => guesses.iter().find(|guess| guess == answer),
+        _ => rank(guesses, candidates, rules, strategy)
+            .first()
+            .map(|score| score.enemy),
+    }
+}
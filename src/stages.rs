@@ -0,0 +1,34 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// The earliest point in world progression an enemy can be met.
+///
+/// Later stages compare as greater.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Serialize,
+)]
+pub enum Stage {
+    #[default]
+    PreHardmode,
+    Hardmode,
+    PostPlantera,
+    PostGolem,
+    PostMoonLord,
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Stage::*;
+
+        let text = match self {
+            PreHardmode => "Pre-Hardmode",
+            Hardmode => "Hardmode",
+            PostPlantera => "Post-Plantera",
+            PostGolem => "Post-Golem",
+            PostMoonLord => "Post-Moon Lord",
+        };
+
+        write!(f, "{text}")
+    }
+}
@@ -1,15 +1,864 @@
-use crate::enemies::list_enemies;
+use crate::{catalogue::Catalogue, enemies::list_enemies};
 
 #[test]
 fn partial_enemy_diff() {
+    use crate::prelude::Verdict;
+
+    let enemies = Catalogue::builtin();
+    let illuminant_bat = enemies.get(137).unwrap();
+    let dreamer_ghoul = enemies.get(527).unwrap();
+    let bad_diff = illuminant_bat.diff(dreamer_ghoul);
+    assert_ne!(bad_diff.biomes.unwrap().verdict, Verdict::Exact);
+
+    let vampire = enemies.get(159).unwrap();
+    let reaper = enemies.get(253).unwrap();
+    let good_diff = vampire.diff(reaper);
+    assert_eq!(good_diff.events.unwrap().verdict, Verdict::Exact);
+}
+
+#[test]
+fn daily_answers_are_stable() {
+    use crate::daily::{Date, Schedule};
+
     let enemies = list_enemies();
-    let illuminant_bat = enemies.iter().find(|e| e.id == 137).unwrap();
-    let dreamer_ghoul = enemies.iter().find(|e| e.id == 527).unwrap();
-    let bad_diff = illuminant_bat.diff(&dreamer_ghoul);
-    assert!(bad_diff.biomes.missing);
+    let epoch = Date::new(2025, 1, 1).unwrap();
+    let schedule = Schedule::new(epoch, enemies.len())
+        .with_salt(7)
+        .with_window(100);
+
+    assert_eq!(Date::from_days(epoch.days()), epoch);
+    assert_eq!(Date::new(1970, 1, 1).unwrap().days(), 0);
+    assert!(Date::new(2025, 2, 29).is_none());
+
+    let answers: Vec<_> = schedule.answers(&enemies).take(365).flatten().collect();
+    for (day, answer) in answers.iter().enumerate() {
+        let recent = &answers[day.saturating_sub(100)..day];
+        assert!(!recent.iter().any(|enemy| enemy.id == answer.id));
+    }
+    let date = Date::from_days(epoch.days() + 200);
+    assert_eq!(schedule.answer(&enemies, date).unwrap().id, answers[200].id);
+    assert_eq!(schedule.puzzle(date), Some(201));
+
+    // Appending to the dataset mustn't change days before the next release.
+    let mut grown = enemies.to_vec();
+    grown.extend(enemies.iter().cloned().map(|mut enemy| {
+        enemy.id += 2000;
+        enemy
+    }));
+    let unreleased: Vec<_> = schedule.answers(&grown).take(300).flatten().collect();
+    assert_eq!(unreleased, answers[..300]);
+    let release = Date::from_days(epoch.days() + 300);
+    let schedule = schedule.with_release(release, grown.len());
+    let regrown: Vec<_> = schedule.answers(&grown).take(300).flatten().collect();
+    assert_eq!(regrown, answers[..300]);
+
+    // A window longer than the dataset only means answers repeat as late as
+    // they can.
+    let never = Schedule::new(epoch, enemies.len()).with_window(usize::MAX);
+    assert!(never
+        .answers(&enemies)
+        .take(3)
+        .all(|answer| answer.is_some()));
+}
 
-    let vampire = enemies.iter().find(|e| e.id == 159).unwrap();
+#[test]
+fn game_session() {
+    use crate::game::{Game, GuessError, Status};
+
+    let enemies = list_enemies();
+    let zombie = enemies.iter().find(|e| e.id == 3).unwrap();
+    let mut game = Game::new(zombie.clone(), 3);
+
+    assert!(game.guess("Blue Slime").is_ok());
+    assert_eq!(
+        game.guess(" blue slime"),
+        Err(GuessError::Duplicate("Blue Slime".to_string()))
+    );
+    assert!(matches!(
+        game.guess("Blue Slimy"),
+        Err(GuessError::Unknown(_))
+    ));
+    assert_eq!(game.status(), Status::InProgress);
+    assert!(game.guess("zombie").unwrap().is_same());
+    assert_eq!(game.status(), Status::Won);
+    assert_eq!(game.guess("Demon Eye"), Err(GuessError::Finished));
+
+    let json = serde_json::to_string(&game).unwrap();
+    let restored: Game = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, game);
+    assert_eq!(restored.guesses().len(), 2);
+}
+
+#[test]
+fn candidates_narrow_down() {
+    use crate::{game::Game, solver::candidates};
+
+    let enemies = list_enemies();
     let reaper = enemies.iter().find(|e| e.id == 253).unwrap();
-    let good_diff = vampire.diff(&reaper);
-    assert!(!good_diff.events.missing);
+    let mut game = Game::new(reaper.clone(), 6);
+    game.guess("Vampire").unwrap();
+
+    let remaining = candidates(&enemies, game.guesses(), game.rules());
+    assert!(remaining.contains(&reaper));
+    assert!(remaining.len() < enemies.len());
+    assert!(remaining
+        .iter()
+        .all(|candidate| candidate.events == reaper.events));
+
+    game.guess("Reaper").unwrap();
+    assert_eq!(candidates(&enemies, game.guesses(), game.rules()), [reaper]);
+}
+
+#[test]
+fn best_guess_splits_candidates() {
+    use crate::{
+        rules::Rules,
+        solver::{best_guess, rank, score, Strategy},
+    };
+
+    let enemies = list_enemies();
+    let rules = Rules::default();
+    let all: Vec<_> = enemies.iter().collect();
+    let ranked = rank(&enemies, &all, &rules, Strategy::Entropy);
+    assert_eq!(ranked.len(), enemies.len());
+    assert!(ranked[0].entropy >= ranked[ranked.len() - 1].entropy);
+
+    let minimax = best_guess(&enemies, &all, &rules, Strategy::Minimax).unwrap();
+    let worst = score(minimax, &all, &rules).worst_case;
+    assert!(enemies
+        .iter()
+        .all(|e| score(e, &all, &rules).worst_case >= worst));
+
+    let single = [&enemies[0]];
+    assert_eq!(
+        best_guess(&enemies, &single, &rules, Strategy::Entropy),
+        Some(&enemies[0])
+    );
+    assert_eq!(best_guess(&enemies, &[], &rules, Strategy::Entropy), None);
+}
+
+#[test]
+fn hard_days_are_spaced_out() {
+    use crate::{
+        daily::{Date, Schedule},
+        rating::{report, Rating},
+        rules::Rules,
+        solver::Strategy,
+    };
+
+    let enemies = list_enemies();
+    let assessments = report(&enemies, &Rules::default(), Strategy::Entropy);
+    assert!(assessments.iter().all(|a| a.guesses > 0));
+    assert!(assessments.iter().any(|a| a.rating == Rating::Hard));
+
+    let hard = assessments
+        .iter()
+        .filter(|a| a.rating == Rating::Hard)
+        .map(|a| a.enemy.id);
+    let epoch = Date::new(2025, 1, 1).unwrap();
+    let schedule = Schedule::new(epoch, enemies.len()).with_hard(epoch, hard);
+    let answers: Vec<_> = schedule.answers(&enemies).take(365).flatten().collect();
+    assert!(answers
+        .windows(2)
+        .all(|pair| !(schedule.is_hard(pair[0].id, epoch) && schedule.is_hard(pair[1].id, epoch))));
+
+    // Spacing hard days out is a preference; it never leaves a day empty.
+    let pool = &enemies[..5];
+    let schedule = Schedule::new(epoch, pool.len())
+        .with_window(2)
+        .with_hard(epoch, pool.iter().skip(1).map(|enemy| enemy.id));
+    assert!(schedule
+        .answers(pool)
+        .take(30)
+        .all(|answer| answer.is_some()));
+}
+
+#[test]
+fn share_grid() {
+    use crate::game::Game;
+
+    let enemies = list_enemies();
+    let reaper = enemies.iter().find(|e| e.id == 253).unwrap();
+    let mut game = Game::new(reaper.clone(), 6);
+    game.guess("Vampire").unwrap();
+    game.guess("Reaper").unwrap();
+
+    // Vampire has more life, defence and coins, the same biome, time, event, layer, rarity
+    // and stage, and shares no kind with the Reaper.
+    assert_eq!(
+        game.share(42),
+        "Eowordle #42 2/6\n🟥⬇️⬇️⬇️🟩🟩🟩🟩🟩🟩🟥\n🟩🟩🟩🟩🟩🟩🟩🟩🟩🟩🟩"
+    );
+}
+
+#[test]
+fn name_lookup() {
+    use crate::search::{distance, normalize, NameIndex};
+
+    assert_eq!(normalize("  Hoppin' JÂCK "), "hoppin jack");
+    assert_eq!(distance("pinkey", "pinky"), 1);
+    assert_eq!(distance("zmobie", "zombie"), 1);
+
+    let enemies = list_enemies();
+    let index = NameIndex::new(&enemies);
+    assert_eq!(index.exact("dr man fly").unwrap().id, 468);
+    assert_eq!(index.exact("Pigron corrupt").unwrap().id, 170);
+    assert!(index.exact("zomb").is_none());
+
+    assert_eq!(index.complete("eater of soul", 5)[0].name, "Eater of Souls");
+    let zombies = index.complete("zombie", 10);
+    assert_eq!(zombies[0].name, "Zombie");
+    assert!(zombies.iter().any(|e| e.name == "Blood Zombie"));
+
+    assert_eq!(index.fuzzy("pinkey", 2)[0].name, "Pinky");
+    assert!(index.fuzzy("qqqqqq", 1).is_empty());
+}
+
+#[test]
+fn catalogue_ids_are_unique() {
+    let enemies = list_enemies();
+    let catalogue = Catalogue::new(enemies.to_vec()).unwrap();
+    assert_eq!(catalogue.len(), enemies.len());
+    assert_eq!(catalogue.get(259).unwrap().name, "Fungi Bulb");
+    assert!(catalogue.get(0).is_none());
+
+    let mut duplicated = enemies.to_vec();
+    duplicated.push(enemies[0].clone());
+    let error = Catalogue::new(duplicated).unwrap_err();
+    assert_eq!(error.id, enemies[0].id);
+}
+
+#[test]
+fn flag_indices_match_declaration_order() {
+    use crate::{
+        biomes::Biome,
+        events::Event,
+        kinds::Kind,
+        layers::Layer,
+        prelude::{Flag, Set},
+        rules::Column,
+    };
+
+    fn check<T: Flag>() {
+        assert!(T::ALL.len() <= 32);
+        assert!(T::ALL
+            .iter()
+            .enumerate()
+            .all(|(index, value)| value.index() as usize == index));
+        let set: Set<T> = T::ALL.iter().copied().collect();
+        assert_eq!(set.len(), T::ALL.len());
+    }
+
+    check::<Biome>();
+    check::<Column>();
+    check::<Event>();
+    check::<Layer>();
+    check::<Kind>();
+}
+
+#[test]
+fn dataset_round_trip() {
+    use crate::{
+        dataset::{parse, write, Format, LoadError},
+        game::{Game, GuessError},
+    };
+
+    let builtin = Catalogue::builtin();
+    let json = write(builtin.enemies(), Format::Json);
+    let loaded = parse(&json, Format::Json).unwrap();
+    assert_eq!(loaded.enemies(), builtin.enemies());
+    #[cfg(feature = "ron")]
+    assert_eq!(
+        parse(&write(builtin.enemies(), Format::Ron), Format::Ron)
+            .unwrap()
+            .enemies(),
+        builtin.enemies()
+    );
+    #[cfg(feature = "toml")]
+    assert_eq!(
+        parse(&write(builtin.enemies(), Format::Toml), Format::Toml)
+            .unwrap()
+            .enemies(),
+        builtin.enemies()
+    );
+
+    let mut modded = builtin.enemies()[..2].to_vec();
+    modded[0].name = "Blue Slimer".into();
+    let modded = parse(&write(&modded, Format::Json), Format::Json).unwrap();
+    let mut game = Game::new(modded.enemies()[1].clone(), 6);
+    assert!(game.guess_in(&modded, "blue slimer").is_ok());
+    assert!(matches!(
+        game.guess_in(&modded, "Zombie"),
+        Err(GuessError::Unknown(_))
+    ));
+
+    let duplicated = write(
+        &[builtin.enemies()[0].clone(), builtin.enemies()[0].clone()],
+        Format::Json,
+    );
+    assert!(matches!(
+        parse(&duplicated, Format::Json),
+        Err(LoadError::Invalid(_))
+    ));
+    assert!(matches!(parse("{", Format::Json), Err(LoadError::Parse(_))));
+}
+
+#[test]
+fn dataset_validation() {
+    use crate::{
+        biomes::Biome,
+        layers::Layer,
+        validate::{validate, Attribute, Problem, Severity},
+    };
+
+    let enemies = Catalogue::builtin().enemies();
+    assert!(validate(enemies)
+        .iter()
+        .all(|issue| issue.severity() == Severity::Warning));
+
+    let mut broken = enemies[..2].to_vec();
+    broken[1].id = broken[0].id;
+    broken[1].name = "BLUE SLIME".into();
+    broken[1].life = 0;
+    broken[1].biomes = vec![].into();
+    broken[0].layers = vec![Layer::Any, Layer::Surface].into();
+    broken[0].biomes = vec![Biome::Forest, Biome::Forest].into();
+    let problems: Vec<_> = validate(&broken)
+        .into_iter()
+        .map(|issue| (issue.index, issue.problem))
+        .collect();
+    assert_eq!(
+        problems,
+        [
+            (0, Problem::AnyWithSpecific(Attribute::Layers)),
+            (0, Problem::Repeated(Attribute::Biomes)),
+            (1, Problem::DuplicateId),
+            (1, Problem::DuplicateName),
+            (1, Problem::ZeroLife),
+            (1, Problem::Empty(Attribute::Biomes)),
+        ]
+    );
+}
+
+#[test]
+fn stage_diff() {
+    use crate::{prelude::OrderingText, stages::Stage};
+
+    let enemies = Catalogue::builtin();
+    let zombie = enemies.get(3).unwrap();
+    let paladin = enemies.get(290).unwrap();
+    assert_eq!(zombie.stage, Stage::PreHardmode);
+    assert_eq!(paladin.stage, Stage::PostPlantera);
+    assert_eq!(zombie.diff(paladin).stage, Some(OrderingText::Less));
+    assert_eq!(paladin.diff(zombie).stage, Some(OrderingText::Greater));
+    assert!(Stage::Hardmode < Stage::PostMoonLord);
+}
+
+#[test]
+fn boss_mode() {
+    use crate::{categories::Category, game::Game, solver::candidates};
+
+    let bosses = Catalogue::builtin().filter(|enemy| enemy.category == Category::Boss);
+    assert!(bosses.len() >= 15);
+    assert!(bosses.get(3).is_none());
+    let moon_lord = bosses.get(398).unwrap();
+    assert!(moon_lord.life > u16::MAX as u32);
+
+    let mut game = Game::new(moon_lord.clone(), 6);
+    assert!(game.guess_in(&bosses, "Zombie").is_err());
+    game.guess_in(&bosses, "Eye of Cthulhu").unwrap();
+    let remaining = candidates(bosses.enemies(), game.guesses(), game.rules());
+    assert!(remaining.contains(&moon_lord));
+    assert!(remaining
+        .iter()
+        .all(|enemy| enemy.category == Category::Boss));
+
+    let pumpking = Catalogue::builtin().get(327).unwrap();
+    assert_eq!(pumpking.category, Category::MiniBoss);
+
+    // The classic game leaves bosses out.
+    let regular = Catalogue::regular();
+    assert_eq!(regular.len() + bosses.len(), Catalogue::builtin().len());
+    assert!(regular.get(327).is_some() && regular.get(398).is_none());
+    assert_eq!(list_enemies().len(), regular.len());
+    let mut game = Game::new(pumpking.clone(), 6);
+    assert!(game.guess("Moon Lord").is_err());
+}
+
+#[test]
+fn kind_diff() {
+    use crate::{kinds::Kind, prelude::Verdict};
+
+    let enemies = Catalogue::builtin();
+    let skeleton_archer = enemies.get(110).unwrap();
+    let goblin_archer = enemies.get(111).unwrap();
+    let diff = skeleton_archer.diff(goblin_archer);
+    let kinds = diff.kinds.unwrap();
+    assert!(kinds.right.contains(Kind::Shooter));
+    assert!(kinds.wrong.contains(Kind::Skeleton));
+    assert_eq!(kinds.verdict, Verdict::Partial);
+    assert!(!diff.is_same());
+}
+
+#[test]
+fn difficulty_stats() {
+    use crate::{
+        game::Game,
+        prelude::{Coins, OrderingText},
+        rules::{Difficulty, Rules},
+    };
+
+    let enemies = Catalogue::builtin();
+    let zombie = enemies.get(3).unwrap();
+    let expert = zombie.stats(Difficulty::Expert);
+    assert_eq!(expert.life, 90);
+    assert_eq!(expert.defence, zombie.defence);
+    assert_eq!(expert.coins, Coins::new(0, 1, 50));
+    assert_eq!(zombie.stats(Difficulty::Master).life, 135);
+    assert_eq!(zombie.stats(Difficulty::Classic).life, zombie.life);
+
+    let eye = enemies.get(4).unwrap();
+    assert_eq!(eye.stats(Difficulty::Master).life, 4641);
+    assert_eq!(
+        enemies.get(50).unwrap().stats(Difficulty::Expert).life,
+        2800
+    );
+    // Bosses off the usual rate carry their own numbers.
+    assert_eq!(
+        enemies.get(35).unwrap().stats(Difficulty::Expert).life,
+        8800
+    );
+    assert_eq!(
+        enemies.get(134).unwrap().stats(Difficulty::Master).life,
+        153000
+    );
+
+    // Regular enemies scale faster than bosses, so the life hint can flip.
+    let hellhound = enemies.get(329).unwrap();
+    let king_slime = enemies.get(50).unwrap();
+    let rules = Rules::default().with_difficulty(Difficulty::Expert);
+    assert_eq!(
+        hellhound.diff(king_slime).life.unwrap().ordering,
+        OrderingText::Less
+    );
+    assert_eq!(
+        hellhound
+            .diff_with(king_slime, &rules)
+            .life
+            .unwrap()
+            .ordering,
+        OrderingText::Greater
+    );
+    let mut game = Game::with_rules(zombie.clone(), 6, rules.clone());
+    game.guess("Zombie").unwrap();
+    assert_eq!(game.rules(), &rules);
+}
+
+#[test]
+fn combat_stats() {
+    use crate::{
+        prelude::OrderingText,
+        rules::{Column, Difficulty, Rules},
+        share,
+    };
+
+    let enemies = Catalogue::builtin();
+    let zombie = enemies.get(3).unwrap();
+    let slime = enemies.get(1).unwrap();
+    let expert = zombie.stats(Difficulty::Expert);
+    assert_eq!((zombie.damage, zombie.knockback_resist), (14, 50));
+    assert_eq!((expert.damage, expert.knockback_resist), (28, 55));
+    let master = zombie.stats(Difficulty::Master);
+    assert_eq!((master.damage, master.knockback_resist), (42, 60));
+
+    // Off unless a front-end asks for them.
+    let diff = zombie.diff(slime);
+    assert_eq!((diff.damage, diff.knockback), (None, None));
+
+    let rules = Rules::default()
+        .with_column(Column::Damage)
+        .with_column(Column::Knockback);
+    let diff = zombie.diff_with(slime, &rules);
+    assert_eq!(
+        diff.damage.as_ref().unwrap().ordering,
+        OrderingText::Greater
+    );
+    assert_eq!(
+        diff.knockback.as_ref().unwrap().ordering,
+        OrderingText::Greater
+    );
+    assert_eq!(
+        share::row(&diff).chars().count(),
+        share::row(&zombie.diff(slime)).chars().count() + 4
+    );
+    assert!(zombie.diff_with(zombie, &rules).is_same());
+}
+
+#[test]
+fn column_rules() {
+    use crate::{
+        rules::{Column, Rules},
+        share,
+    };
+
+    let enemies = Catalogue::builtin();
+    let zombie = enemies.get(3).unwrap();
+    let slime = enemies.get(1).unwrap();
+
+    let easy = Rules::default().without_column(Column::Coins);
+    let diff = zombie.diff_with(slime, &easy);
+    assert_eq!(diff.coins, None);
+    assert!(diff.life.is_some());
+
+    // Only what is compared has to match.
+    let names_only = Rules::default().with_columns([]);
+    let mut twin = zombie.clone();
+    twin.life += 1;
+    assert!(twin.diff_with(zombie, &names_only).is_same());
+    assert!(!twin.diff_with(zombie, &Rules::default()).is_same());
+    assert_eq!(share::row(&slime.diff_with(zombie, &names_only)), "🟥");
+
+    let json = serde_json::to_string(&easy).unwrap();
+    assert_eq!(serde_json::from_str::<Rules>(&json).unwrap(), easy);
+    assert_eq!(
+        serde_json::from_str::<Rules>("{}").unwrap(),
+        Rules::default()
+    );
+}
+
+#[test]
+fn proximity_hints() {
+    use crate::{
+        prelude::{Proximity, Values},
+        rules::{Column, Difficulty, Rules, Thresholds},
+    };
+
+    let thresholds = Thresholds::default();
+    assert_eq!(Proximity::new(100, 100, &thresholds), Proximity::Exact);
+    assert_eq!(Proximity::new(91, 100, &thresholds), Proximity::Close);
+    assert_eq!(Proximity::new(125, 100, &thresholds), Proximity::Near);
+    assert_eq!(Proximity::new(200, 100, &thresholds), Proximity::Far);
+    assert_eq!(Proximity::new(1, 0, &thresholds), Proximity::Far);
+    let strict = Thresholds { close: 1, near: 5 };
+    assert_eq!(Proximity::new(91, 100, &strict), Proximity::Far);
+
+    let enemies = Catalogue::builtin();
+    let zombie = enemies.get(3).unwrap();
+    let slime = enemies.get(1).unwrap();
+    let life = zombie.diff(slime).life.unwrap();
+    assert_eq!((life.proximity, life.values), (None, None));
+
+    let rules = Rules::default()
+        .with_proximity(thresholds)
+        .with_deltas(true);
+    let life = zombie.diff_with(slime, &rules).life.unwrap();
+    assert_eq!(life.proximity, Some(Proximity::Far));
+    assert_eq!(life.delta(), Some(20));
+    assert_eq!(life.ratio(), Some(1.8));
+
+    // The stored values follow the rules, so nothing has to be passed back in.
+    let expert = rules.clone().with_difficulty(Difficulty::Expert);
+    let life = zombie.diff_with(slime, &expert).life.unwrap();
+    assert_eq!(
+        life.values,
+        Some(Values {
+            guess: 90,
+            answer: 50
+        })
+    );
+    assert_eq!(life.delta(), Some(40));
+
+    let bunny = enemies.get(46).unwrap();
+    let damage = bunny
+        .diff_with(bunny, &rules.with_column(Column::Damage))
+        .damage
+        .unwrap();
+    assert_eq!(damage.ratio(), Some(1.0));
+    assert_eq!(
+        Values {
+            guess: 1,
+            answer: 0
+        }
+        .ratio(),
+        f64::INFINITY
+    );
+}
+
+#[test]
+fn set_diff_verdicts() {
+    use crate::{
+        biomes::Biome,
+        prelude::{Diff, Verdict},
+    };
+
+    // Order and repeats don't matter.
+    let diff = Diff::with(
+        &[Biome::Desert, Biome::Forest, Biome::Forest],
+        &[Biome::Forest, Biome::Desert],
+    );
+    assert_eq!(diff.verdict, Verdict::Exact);
+    assert_eq!(diff.missing, 0);
+
+    let diff = Diff::with(
+        &[Biome::Forest, Biome::Forest],
+        &[Biome::Forest, Biome::Desert],
+    );
+    assert_eq!(diff.verdict, Verdict::Partial);
+    assert!(diff.wrong.is_empty());
+    assert_eq!(diff.missing, 1);
+
+    let diff = Diff::with(&[Biome::Snow], &[Biome::Forest, Biome::Desert]);
+    assert_eq!(diff.verdict, Verdict::None);
+    assert_eq!((diff.wrong.len(), diff.missing), (1, 2));
+
+    assert_eq!(Diff::<Biome>::with(&[], &[]).verdict, Verdict::Exact);
+    assert_eq!(Diff::with(&[], &[Biome::Snow]).verdict, Verdict::None);
+}
+
+#[test]
+fn win_check() {
+    use crate::{biomes::Biome, prelude::Rarity};
+
+    let zombie = Catalogue::builtin().get(3).unwrap();
+    assert!(zombie.diff(zombie).is_same());
+
+    // A copy under another id can't be told apart, but isn't the answer.
+    let mut twin = zombie.clone();
+    twin.id = u16::MAX;
+    let diff = twin.diff(zombie);
+    assert!(diff.is_indistinguishable());
+    assert!(!diff.is_same());
+
+    let mut rare = zombie.clone();
+    rare.rarity = Rarity::Rare;
+    assert!(!rare.diff(zombie).is_same());
+
+    // Guessing only some of the answer's biomes isn't a match.
+    let mut roaming = zombie.clone();
+    roaming.biomes = vec![Biome::Any, Biome::Snow].into();
+    assert!(!zombie.diff(&roaming).is_same());
+}
+
+#[test]
+fn ambiguous_enemies() {
+    use crate::{
+        ambiguity::ambiguous_groups,
+        daily::{Date, Schedule},
+        rules::Rules,
+    };
+
+    let enemies = list_enemies();
+    let rules = Rules::default();
+    let groups = ambiguous_groups(&enemies, &rules);
+    for group in &groups {
+        for enemy in &group[1..] {
+            assert!(enemy.diff_with(group[0], &rules).is_indistinguishable());
+        }
+    }
+
+    // Two copies of an enemy under different ids always clash.
+    let mut twin = enemies[0].clone();
+    twin.id = u16::MAX;
+    let doubled = [enemies[0].clone(), enemies[1].clone(), twin];
+    let groups = ambiguous_groups(&doubled, &rules);
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].len(), 2);
+
+    let epoch = Date::new(2024, 1, 1).unwrap();
+    let excluded: Vec<_> = groups.iter().flatten().map(|enemy| enemy.id).collect();
+    let schedule = Schedule::new(epoch, doubled.len()).with_exclude(epoch, excluded);
+    assert!(schedule
+        .answers(&doubled)
+        .take(10)
+        .all(|answer| answer.unwrap().id == doubled[1].id));
+
+    // Excluded enemies don't count towards how long the window can be.
+    let pool = &enemies[..5];
+    let schedule = Schedule::new(epoch, pool.len())
+        .with_window(3)
+        .with_exclude(epoch, [pool[0].id, pool[1].id]);
+    let answers: Vec<_> = schedule.answers(pool).take(30).collect();
+    assert!(answers.iter().all(|answer| answer.is_some()));
+    assert!(answers
+        .windows(2)
+        .all(|pair| pair[0].unwrap().id != pair[1].unwrap().id));
+
+    // Excluding enemies later on leaves the days before untouched.
+    let schedule = Schedule::new(epoch, enemies.len()).with_window(60);
+    let before: Vec<_> = schedule.answers(&enemies).take(365).flatten().collect();
+    let excluded: Vec<_> = ambiguous_groups(&enemies, &rules)
+        .into_iter()
+        .flatten()
+        .map(|enemy| enemy.id)
+        .collect();
+    let date = Date::from_days(epoch.days() + 200);
+    let schedule = schedule.with_exclude(date, excluded.iter().copied());
+    let after: Vec<_> = schedule.answers(&enemies).take(365).flatten().collect();
+    assert_eq!(after[..200], before[..200]);
+    assert!(after[200..]
+        .iter()
+        .all(|enemy| !excluded.contains(&enemy.id)));
+}
+
+#[test]
+fn rarity_direction() {
+    use crate::prelude::{OrderingText, Rarity};
+
+    assert!(Rarity::Common < Rarity::Legendary);
+    assert_eq!(Rarity::VeryRare.stars(), 4);
+    assert_eq!(Rarity::from_stars(4), Some(Rarity::VeryRare));
+    assert_eq!(Rarity::from_stars(0), None);
+
+    let enemies = Catalogue::builtin();
+    let zombie = enemies.get(3).unwrap();
+    let tim = enemies.get(45).unwrap();
+    let king_slime = enemies.get(50).unwrap();
+    assert_eq!(tim.rarity, Rarity::VeryRare);
+    assert_eq!(king_slime.rarity, Rarity::Legendary);
+    // The answer is rarer than the guess.
+    assert_eq!(zombie.diff(tim).rarity, Some(OrderingText::Less));
+    assert_eq!(tim.diff(zombie).rarity, Some(OrderingText::Greater));
+}
+
+#[test]
+fn coin_arithmetic() {
+    use crate::prelude::{Coins, ParseCoinsError};
+
+    let coins = Coins::new(1, 50, 0);
+    assert_eq!(coins.to_string(), "1 gold 50 silver");
+    assert_eq!("1 gold 50 silver".parse::<Coins>(), Ok(coins.clone()));
+    assert_eq!("1g 50s".parse::<Coins>(), Ok(coins.clone()));
+    assert_eq!("150 silver".parse::<Coins>(), Ok(coins.clone()));
+    assert_eq!("Nothing".parse::<Coins>(), Ok(Coins::new(0, 0, 0)));
+    assert_eq!(
+        "2 rubies".parse::<Coins>(),
+        Err(ParseCoinsError::Unit("rubies".to_string()))
+    );
+    assert_eq!("".parse::<Coins>(), Err(ParseCoinsError::Empty));
+
+    let platinum = Coins::new(99, 50, 0) + Coins::new(0, 50, 0);
+    assert_eq!(platinum.platinum, 1);
+    assert_eq!(platinum.to_string(), "1 platinum");
+    assert_eq!(Coins::new(0, 150, 0).gold, 1);
+    let unnormalized = Coins {
+        platinum: 0,
+        gold: 0,
+        silver: 150,
+        copper: 0,
+    };
+    assert_eq!(unnormalized.normalized().gold, 1);
+    assert_eq!(unnormalized.to_string(), "1 gold 50 silver");
+    let full = Coins::new(255, 255, 255).with_platinum(255);
+    assert_eq!(
+        (full.clone() + Coins::new(0, 0, 0)).as_copper(),
+        full.as_copper()
+    );
+    assert!(Coins::new(0, 0, 1) < Coins::new(0, 1, 0));
+    let total: Coins = [Coins::new(0, 0, 60), Coins::new(0, 0, 40)].iter().sum();
+    assert_eq!(total, Coins::new(0, 1, 0));
+
+    // Amounts too large to hold saturate instead of wrapping.
+    assert_eq!(Coins::from_copper(usize::MAX), Coins::MAX);
+    assert_eq!(Coins::MAX.as_copper(), 255_999_999);
+    assert_eq!(Coins::MAX + Coins::new(0, 0, 1), Coins::MAX);
+    let hoard: Coins = [Coins::MAX, Coins::MAX].iter().sum();
+    assert_eq!(hoard, Coins::MAX);
+
+    assert_eq!(serde_json::to_string(&coins).unwrap(), "15000");
+    let old = r#"{"gold":1,"silver":50,"copper":0}"#;
+    assert_eq!(serde_json::from_str::<Coins>(old).unwrap(), coins);
+    let old = serde_json::from_str::<Coins>(r#"{"gold":0,"silver":150,"copper":0}"#).unwrap();
+    assert_eq!((old.gold, old.silver), (1, 50));
+    assert_eq!(serde_json::from_str::<Coins>("\"1g 50s\"").unwrap(), coins);
+}
+
+#[test]
+fn coin_ranges() {
+    use crate::{
+        prelude::{CoinRange, Coins, OrderingText},
+        rules::{CoinBasis, Difficulty, Rules},
+    };
+
+    let enemies = Catalogue::builtin();
+    let zombie = enemies.get(3).unwrap();
+    let range = zombie.drop_range(Difficulty::Classic);
+    assert_eq!(
+        range,
+        CoinRange::new(Coins::new(0, 0, 48), Coins::new(0, 1, 72))
+    );
+    assert_eq!(range.to_string(), "48 copper to 1 silver 72 copper");
+    assert!(range.contains(&zombie.coins));
+    assert_eq!(
+        zombie.drop_range(Difficulty::Expert).max,
+        Coins::new(0, 4, 32)
+    );
+
+    // Lucky rolls only ever raise a drop, so the midpoint sits above the
+    // listed value, and a stored range moves it further.
+    assert_eq!(range.midpoint(), Coins::new(0, 1, 10));
+    let mut lucky = zombie
+        .clone()
+        .with_coin_range(CoinRange::new(Coins::new(0, 0, 60), Coins::new(0, 3, 0)));
+    lucky.id = u16::MAX;
+    assert_eq!(
+        lucky.drop_range(Difficulty::Master).min,
+        Coins::new(0, 1, 50)
+    );
+    assert!(lucky.diff(zombie).coins.unwrap().is_eq());
+    let rules = Rules::default().with_coin_basis(CoinBasis::Midpoint);
+    assert_eq!(
+        lucky.diff_with(zombie, &rules).coins.unwrap().ordering,
+        OrderingText::Greater
+    );
+}
+
+#[test]
+fn time_of_day() {
+    use crate::{biomes::Biome, prelude::Enemy, times::Time};
+
+    let enemies = Catalogue::builtin();
+    let zombie = enemies.get(3).unwrap();
+    let slime = enemies.get(1).unwrap();
+    assert_eq!(zombie.time, Time::Night);
+    assert_eq!(&*zombie.biomes, &[Biome::Any]);
+    assert_eq!(zombie.diff(slime).time, Some(false));
+    assert_eq!(zombie.diff(zombie).time, Some(true));
+
+    // Datasets from before the split listed the time among the biomes.
+    let mut json = serde_json::to_value(zombie).unwrap();
+    json.as_object_mut().unwrap().remove("time");
+    json["biomes"] = serde_json::json!(["Any", "Night"]);
+    assert_eq!(
+        &serde_json::from_value::<Enemy>(json.clone()).unwrap(),
+        zombie
+    );
+    json["biomes"] = serde_json::json!(["Night"]);
+    assert_eq!(
+        &serde_json::from_value::<Enemy>(json.clone()).unwrap(),
+        zombie
+    );
+    json["biomes"] = serde_json::json!(["Day", "Night"]);
+    let always = serde_json::from_value::<Enemy>(json.clone()).unwrap();
+    assert_eq!(
+        (always.time, &*always.biomes),
+        (Time::Any, &[Biome::Any][..])
+    );
+
+    // A time given outright has to agree with the one in the biomes.
+    json["time"] = serde_json::json!("Day");
+    json["biomes"] = serde_json::json!(["Night"]);
+    assert!(serde_json::from_value::<Enemy>(json.clone()).is_err());
+    json["biomes"] = serde_json::json!(["Forset"]);
+    let typo = serde_json::from_value::<Enemy>(json).unwrap_err();
+    assert!(typo.to_string().contains("`Forest`"));
+    assert!(typo.to_string().contains("`Night`"));
+
+    // Exactly what 0.0.1 wrote.
+    let old = r#"{"id":3,"name":"Zombie","life":45,"defence":6,"coins":{"gold":0,"silver":0,"copper":60},"biomes":["Any","Night"],"events":[],"layers":["Surface"],"rarity":"Common"}"#;
+    let old = serde_json::from_str::<Enemy>(old).unwrap();
+    assert_eq!(
+        (old.id, old.time, &*old.biomes),
+        (3, Time::Night, &[Biome::Any][..])
+    );
+    assert_eq!((old.life, &old.coins), (zombie.life, &zombie.coins));
 }
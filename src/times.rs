@@ -0,0 +1,24 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+/// When an enemy spawns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Deserialize, Serialize)]
+pub enum Time {
+    #[default]
+    Any,
+    Day,
+    Night,
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Time::Any => "Any time",
+            Time::Day => "Day",
+            Time::Night => "Night",
+        };
+
+        write!(f, "{text}")
+    }
+}
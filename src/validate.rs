@@ -0,0 +1,186 @@
+use std::{collections::HashMap, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{biomes::Biome, layers::Layer, prelude::Enemy, search::normalize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// One of the list attributes of an [`Enemy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Attribute {
+    Biomes,
+    Events,
+    Layers,
+    Kinds,
+}
+
+impl Display for Attribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = match self {
+            Attribute::Biomes => "biomes",
+            Attribute::Events => "events",
+            Attribute::Layers => "layers",
+            Attribute::Kinds => "kinds",
+        };
+
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Problem {
+    /// Another enemy already has this id.
+    DuplicateId,
+    /// Another enemy's name matches this one once normalized, see [`normalize`].
+    DuplicateName,
+    ZeroLife,
+    /// Knockback resistance is a percentage, so it can't exceed 100.
+    KnockbackResist,
+    /// The least a kill can drop is more than the most it can.
+    CoinRange,
+    /// Every enemy spawns somewhere and is some kind of creature, so biomes,
+    /// layers and kinds can't be empty.
+    Empty(Attribute),
+    /// `Any` listed alongside specific values.
+    AnyWithSpecific(Attribute),
+    /// The same value is listed more than once.
+    Repeated(Attribute),
+}
+
+impl Problem {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Problem::DuplicateId
+            | Problem::DuplicateName
+            | Problem::ZeroLife
+            | Problem::KnockbackResist
+            | Problem::CoinRange
+            | Problem::Empty(_) => Severity::Error,
+            Problem::AnyWithSpecific(_) | Problem::Repeated(_) => Severity::Warning,
+        }
+    }
+}
+
+impl Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::DuplicateId => write!(f, "id is used by another enemy"),
+            Problem::DuplicateName => write!(f, "name is used by another enemy"),
+            Problem::ZeroLife => write!(f, "life is zero"),
+            Problem::KnockbackResist => write!(f, "knockback resistance is above 100%"),
+            Problem::CoinRange => write!(f, "coin range ends below where it starts"),
+            Problem::Empty(attribute) => write!(f, "no {attribute}"),
+            Problem::AnyWithSpecific(attribute) => {
+                write!(f, "{attribute} mix Any with specific values")
+            }
+            Problem::Repeated(attribute) => write!(f, "{attribute} repeat a value"),
+        }
+    }
+}
+
+/// A problem with one entry of a dataset.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct Issue {
+    /// Position of the enemy in the dataset.
+    pub index: usize,
+    pub id: u16,
+    pub name: String,
+    pub problem: Problem,
+}
+
+impl Issue {
+    pub fn severity(&self) -> Severity {
+        self.problem.severity()
+    }
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity() {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+
+        write!(
+            f,
+            "{severity}: {} ({}): {}",
+            self.name, self.id, self.problem
+        )
+    }
+}
+
+/// Checks every enemy in a dataset, returning issues in dataset order.
+pub fn validate(enemies: &[Enemy]) -> Vec<Issue> {
+    let mut issues = vec![];
+    let mut ids = HashMap::new();
+    let mut names = HashMap::new();
+
+    for (index, enemy) in enemies.iter().enumerate() {
+        let mut problems = vec![];
+        if ids.insert(enemy.id, index).is_some() {
+            problems.push(Problem::DuplicateId);
+        }
+        if names.insert(normalize(&enemy.name), index).is_some() {
+            problems.push(Problem::DuplicateName);
+        }
+        if enemy.life == 0 {
+            problems.push(Problem::ZeroLife);
+        }
+        if enemy.knockback_resist > 100 {
+            problems.push(Problem::KnockbackResist);
+        }
+        if let Some(range) = &enemy.coin_range {
+            if range.min > range.max {
+                problems.push(Problem::CoinRange);
+            }
+        }
+        if enemy.biomes.is_empty() {
+            problems.push(Problem::Empty(Attribute::Biomes));
+        }
+        if enemy.layers.is_empty() {
+            problems.push(Problem::Empty(Attribute::Layers));
+        }
+        if enemy.kinds.is_empty() {
+            problems.push(Problem::Empty(Attribute::Kinds));
+        }
+        if enemy.biomes.contains(&Biome::Any) && enemy.biomes.len() > 1 {
+            problems.push(Problem::AnyWithSpecific(Attribute::Biomes));
+        }
+        if enemy.layers.contains(&Layer::Any) && enemy.layers.len() > 1 {
+            problems.push(Problem::AnyWithSpecific(Attribute::Layers));
+        }
+        if has_repeats(&enemy.biomes) {
+            problems.push(Problem::Repeated(Attribute::Biomes));
+        }
+        if has_repeats(&enemy.events) {
+            problems.push(Problem::Repeated(Attribute::Events));
+        }
+        if has_repeats(&enemy.layers) {
+            problems.push(Problem::Repeated(Attribute::Layers));
+        }
+        if has_repeats(&enemy.kinds) {
+            problems.push(Problem::Repeated(Attribute::Kinds));
+        }
+
+        issues.extend(problems.into_iter().map(|problem| Issue {
+            index,
+            id: enemy.id,
+            name: enemy.name.to_string(),
+            problem,
+        }));
+    }
+
+    issues
+}
+
+fn has_repeats<T: PartialEq>(items: &[T]) -> bool {
+    items
+        .iter()
+        .enumerate()
+        .any(|(i, item)| items[..i].contains(item))
+}